@@ -0,0 +1,122 @@
+/// Generates the solution registry that is compiled into the main binary.
+/// Every solution binary in `src/bin` is included as a module, and its `SOLUTION` entry is added to `SOLUTIONS`.
+///
+/// Also generates one test per named example case in `data/{year}/examples/{day}/`, which the `solution!` macro includes.
+use std::{env, fs, path::Path};
+
+fn is_solution_name(name: &str) -> bool {
+    // solution binaries are named `{year}-{day}`, e.g. `2023-01`.
+    name.len() == 7
+        && name.as_bytes()[4] == b'-'
+        && name
+            .chars()
+            .enumerate()
+            .all(|(i, c)| i == 4 || c.is_ascii_digit())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut names: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            let name = path.file_stem()?.to_str()?.to_string();
+            is_solution_name(&name).then_some(name)
+        })
+        .collect();
+
+    names.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for name in &names {
+        let module = format!("solution_{}", name.replace('-', "_"));
+        let path = bin_dir.join(format!("{name}.rs"));
+        modules.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {:?}]\nmod {module};\n",
+            path.display().to_string()
+        ));
+        entries.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+
+    let registry = format!(
+        "{modules}\n/// Every solution found in `src/bin`, ordered by year and day.\npub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[\n{entries}];\n"
+    );
+
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    fs::write(out_dir.join("solutions.rs"), registry).unwrap();
+
+    let examples_dir = out_dir.join("examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+
+    for name in &names {
+        let (year, day) = name.split_at(4);
+        let day = &day[1..];
+
+        let cases_dir = Path::new(&manifest_dir)
+            .join("data")
+            .join(year)
+            .join("examples");
+        // cargo treats a missing path as changed and would rerun this script on every build. Without examples, the year
+        // is watched instead, to notice when they are added.
+        let watched = [cases_dir.as_path(), cases_dir.parent().unwrap()]
+            .into_iter()
+            .find(|dir| dir.is_dir());
+        if let Some(dir) = watched {
+            println!("cargo:rerun-if-changed={}", dir.display());
+        }
+
+        let tests = generate_example_tests(&cases_dir.join(day));
+
+        // the `solution!` macro refers to the file by the day as written, e.g. `1` or `01`.
+        let unpadded = day.trim_start_matches('0');
+        for day in [day, unpadded] {
+            fs::write(examples_dir.join(format!("{year}-{day}.rs")), &tests).unwrap();
+        }
+    }
+}
+
+/// Generates a test for every `{name}.txt` in `dir`.
+fn generate_example_tests(dir: &Path) -> String {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return String::new();
+    };
+
+    let mut cases: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+
+    cases.sort();
+
+    cases
+        .iter()
+        .map(|case| {
+            let ident: String = case
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            format!("#[test]\nfn example_{ident}() {{\n    super::check_example({case:?});\n}}\n")
+        })
+        .collect()
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use regex::Regex;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
@@ -64,14 +64,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(142));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(281));
     }
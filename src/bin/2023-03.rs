@@ -3,7 +3,7 @@ use std::ops::Range;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 struct Symbol {
     c: char,
@@ -86,13 +86,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(467835));
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use tuple::Map;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 fn split_into_set(input: &str) -> HashSet<u32> {
     input
@@ -53,13 +53,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(30));
     }
 }
@@ -3,7 +3,7 @@ use std::ops::Range;
 use itertools::Itertools;
 use rangemap::RangeMap;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 fn parse_nums(line: &str) -> Vec<i64> {
     line.split_ascii_whitespace()
@@ -100,13 +100,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 }
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use tuple::Map;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 fn parse_vals(line: &str) -> Vec<f64> {
     line.split_ascii_whitespace()
@@ -18,7 +18,7 @@ fn parse_single_val(line: &str) -> f64 {
 fn get_n_times(time: f64, dist: f64) -> u64 {
     let min_time = time / 2.0 - (f64::sqrt(time * time / 4.0 - dist).max(0.0));
     let ceil_time = (min_time + 1e-8).ceil() as u64;
-    (time as u64 - 2 * ceil_time + 1).max(0)
+    (time as u64 + 1).saturating_sub(2 * ceil_time)
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -46,13 +46,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(288));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(71503));
     }
 }
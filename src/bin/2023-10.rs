@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use advent_of_code::geom::{Dir4, Point};
+use advent_of_code::grid::Pos;
+use advent_of_code::polygon::Polygon;
+use advent_of_code::search::{bfs, Search};
+use itertools::Itertools;
+use phf::phf_map;
+
+advent_of_code::solution!(2023, 10);
+
+type Pipe = [Dir4; 2];
+
+static PIPES: phf::Map<char, Pipe> = phf_map! {
+    '|' => [Dir4::Up, Dir4::Down],
+    '-' => [Dir4::Right, Dir4::Left],
+    'L' => [Dir4::Up, Dir4::Right],
+    'J' => [Dir4::Up, Dir4::Left],
+    '7' => [Dir4::Down, Dir4::Left],
+    'F' => [Dir4::Down, Dir4::Right],
+};
+
+type Coord = Pos;
+type Neighbors = [Coord; 2];
+
+/// Steps are clamped to the top and left edge.
+fn step(coord: Coord, dir: Dir4) -> Coord {
+    Point::from(coord)
+        .checked_step(dir)
+        .map_or(coord, Coord::from)
+}
+
+fn get_start(input: &str) -> Coord {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(i, l)| {
+            l.char_indices()
+                .filter_map(move |(j, c)| if c == 'S' { Some((i, j)) } else { None })
+        })
+        .next()
+        .unwrap()
+}
+
+fn get_network(input: &str, start: Coord) -> HashMap<Coord, Neighbors> {
+    let mut network: HashMap<Coord, Neighbors> =
+        HashMap::from_iter(input.lines().enumerate().flat_map(|(i, l)| {
+            l.char_indices().filter_map(move |(j, c)| {
+                PIPES.get(&c).map(|p| ((i, j), p.map(|d| step((i, j), d))))
+            })
+        }));
+
+    let start_neighbors: Neighbors = network
+        .iter()
+        .filter_map(|(k, v)| if v.contains(&start) { Some(*k) } else { None })
+        .collect_vec()
+        .try_into()
+        .unwrap();
+    network.insert(start, start_neighbors);
+    network
+}
+
+/// Walks the loop through the start, with the distance of every pipe from the start.
+fn get_loop(network: &HashMap<Coord, Neighbors>, start: Coord) -> Search<Coord, usize> {
+    bfs([start], |c| network[c], |_| false)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let start = get_start(input);
+    let network = get_network(input, start);
+
+    get_loop(&network, start)
+        .iter()
+        .map(|(_, d)| d as u32)
+        .max()
+}
+
+/// The pipes of the loop in the order they are walked, starting and ending next to the start.
+fn walk_loop(network: &HashMap<Coord, Neighbors>, start: Coord) -> Vec<Coord> {
+    let mut pipes = vec![];
+    let (mut prev, mut pos) = (start, network[&start][0]);
+    while pos != start {
+        pipes.push(pos);
+        let next = network[&pos]
+            .into_iter()
+            .find(|&next| next != prev)
+            .unwrap();
+        (prev, pos) = (pos, next);
+    }
+    pipes.push(start);
+    pipes
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let start = get_start(input);
+    let network = get_network(input, start);
+
+    // the tiles are the lattice points, the pipes of the loop are its boundary.
+    let vertices = walk_loop(&network, start)
+        .into_iter()
+        .map(|(i, j)| Point::new(j as isize, i as isize))
+        .collect();
+    Some(Polygon::new(vertices).interior_points() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        ));
+        assert_eq!(result, Some(10));
+    }
+}
@@ -1,8 +1,10 @@
+use advent_of_code::geom::Point;
+use advent_of_code::template::params::Params;
 use itertools::Itertools;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11, params);
 
-type Coords = [usize; 2];
+type Coords = Point<usize>;
 
 fn parse_galaxies(input: &str) -> Vec<Coords> {
     input
@@ -10,17 +12,17 @@ fn parse_galaxies(input: &str) -> Vec<Coords> {
         .enumerate()
         .flat_map(|(i, l)| {
             l.char_indices()
-                .filter_map(move |(j, c)| if c == '#' { Some([i, j]) } else { None })
+                .filter_map(move |(j, c)| (c == '#').then_some(Point::new(j, i)))
         })
         .collect()
 }
 
 fn unique_rows(coords: &[Coords]) -> Vec<usize> {
-    coords.iter().map(|[i, _]| *i).sorted().unique().collect()
+    coords.iter().map(|p| p.y).sorted().unique().collect()
 }
 
 fn unique_cols(coords: &[Coords]) -> Vec<usize> {
-    coords.iter().map(|[_, j]| *j).sorted().unique().collect()
+    coords.iter().map(|p| p.x).sorted().unique().collect()
 }
 
 fn adjust_galaxies(
@@ -31,20 +33,16 @@ fn adjust_galaxies(
 ) -> Vec<Coords> {
     galaxies
         .iter()
-        .map(|&[i, j]| {
-            [
-                i + (scale - 1) * (i + 1 - rows.partition_point(|&r| r < i)),
+        .map(|&Point { x: j, y: i }| {
+            Point::new(
                 j + (scale - 1) * (j + 1 - cols.partition_point(|&c| c < j)),
-            ]
+                i + (scale - 1) * (i + 1 - rows.partition_point(|&r| r < i)),
+            )
         })
         .collect()
 }
 
-pub fn manhatten_distance([x1, x2]: &Coords, [y1, y2]: &Coords) -> usize {
-    x1.abs_diff(*y1) + x2.abs_diff(*y2)
-}
-
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, _params: &Params) -> Option<usize> {
     let galaxies = parse_galaxies(input);
 
     let rows = unique_rows(&galaxies);
@@ -55,23 +53,24 @@ pub fn part_one(input: &str) -> Option<usize> {
         adjusted_galaxies
             .iter()
             .tuple_combinations()
-            .map(|(x, y)| manhatten_distance(x, y))
+            .map(|(&a, &b)| a.manhattan(b))
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
     let galaxies = parse_galaxies(input);
 
     let rows = unique_rows(&galaxies);
     let cols = unique_cols(&galaxies);
 
-    let adjusted_galaxies = adjust_galaxies(&galaxies, &rows, &cols, 1000000);
+    let adjusted_galaxies =
+        adjust_galaxies(&galaxies, &rows, &cols, params.get_or("factor", 1000000));
     Some(
         adjusted_galaxies
             .iter()
             .tuple_combinations()
-            .map(|(x, y)| manhatten_distance(x, y))
+            .map(|(&a, &b)| a.manhattan(b))
             .sum(),
     )
 }
@@ -82,13 +81,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
         assert_eq!(result, Some(374));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
         assert_eq!(result, Some(82000210));
     }
 }
@@ -1,21 +1,10 @@
-use itertools::Itertools;
+use advent_of_code::grid::Grid;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
-type Pattern = Vec<Vec<char>>;
+type Pattern = Grid<char>;
 fn parse_pattern(input: &str) -> Pattern {
-    input.lines().map(|l| l.chars().collect()).collect()
-}
-
-fn transpose(pattern: &Pattern) -> Pattern {
-    let mut res = vec![vec![0 as char; pattern.len()]; pattern[0].len()];
-    for (i, row) in pattern.iter().enumerate() {
-        for (j, e) in row.iter().enumerate() {
-            res[j][i] = *e;
-        }
-    }
-
-    res
+    Grid::parse(input, |c| c)
 }
 
 fn vertical(pattern: &Pattern) -> Vec<usize> {
@@ -24,12 +13,12 @@ fn vertical(pattern: &Pattern) -> Vec<usize> {
 
 fn vertical_with_filter(pattern: &Pattern, filter: Option<usize>) -> Vec<usize> {
     let mut idxs = Vec::new();
-    for i in 1..pattern[0].len() {
+    for i in 1..pattern.cols() {
         if i != filter.unwrap_or(0) {
             idxs.push(i);
         }
     }
-    pattern.iter().fold(idxs, |acc, e| {
+    pattern.iter_rows().fold(idxs, |acc, e| {
         acc.into_iter()
             .filter(|&i| e[0..i].iter().rev().zip(e[i..].iter()).all(|(x, y)| x == y))
             .collect()
@@ -37,11 +26,11 @@ fn vertical_with_filter(pattern: &Pattern, filter: Option<usize>) -> Vec<usize>
 }
 
 fn horizontal(pattern: &Pattern) -> Vec<usize> {
-    vertical(&transpose(pattern))
+    vertical(&pattern.transpose())
 }
 
 fn horizontal_with_filter(pattern: &Pattern, filter: Option<usize>) -> Vec<usize> {
-    vertical_with_filter(&transpose(pattern), filter)
+    vertical_with_filter(&pattern.transpose(), filter)
 }
 
 fn reflections(pattern: &Pattern) -> [Option<usize>; 2] {
@@ -82,11 +71,10 @@ pub fn part_two(input: &str) -> Option<usize> {
             .map(|l| {
                 let p = parse_pattern(l);
                 let [h, v] = reflections(&p);
-                (0..p.len())
-                    .cartesian_product(0..p[0].len())
-                    .map(|(i, j)| {
+                p.positions()
+                    .map(|pos| {
                         let mut p = p.clone();
-                        p[i][j] = if p[i][j] == '.' { '#' } else { '.' };
+                        p[pos] = if p[pos] == '.' { '#' } else { '.' };
                         p
                     })
                     .filter_map(|p| {
@@ -110,13 +98,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(400));
     }
 }
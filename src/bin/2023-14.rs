@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
-use grid::Grid;
+use advent_of_code::grid::Grid;
+use advent_of_code::template::params::Params;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14, params);
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
 enum Rock {
@@ -10,29 +11,21 @@ enum Rock {
     Cube,
 }
 
-use itertools::Itertools;
 use Rock::*;
 
 type Rocks = Grid<Option<Rock>>;
 fn parse_rocks(input: &str) -> Rocks {
-    let mut rocks = Rocks::new(input.lines().count(), input.lines().next().unwrap().len());
-    for (i, l) in input.lines().enumerate() {
-        for (j, c) in l.char_indices() {
-            rocks[(i, j)] = match c {
-                'O' => Some(Round),
-                '#' => Some(Cube),
-                '.' => None,
-                _ => panic!("Unknown rock"),
-            }
-        }
-    }
-
-    rocks
+    Grid::parse(input, |c| match c {
+        'O' => Some(Round),
+        '#' => Some(Cube),
+        '.' => None,
+        _ => panic!("Unknown rock"),
+    })
 }
 
 fn do_west_shift(rocks: &mut Rocks) {
-    for i in 0..rocks.size().0 {
-        let mut row = rocks.iter_row(i).copied().collect_vec();
+    for i in 0..rocks.rows() {
+        let row = rocks.row_mut(i);
         let mut j = 0;
         for i in 0..row.len() {
             match row[i] {
@@ -46,24 +39,20 @@ fn do_west_shift(rocks: &mut Rocks) {
                 None => {}
             }
         }
-
-        for (x, y) in rocks.iter_row_mut(i).zip(row.iter()) {
-            *x = *y;
-        }
     }
 }
 
 fn do_cycle(rocks: &mut Rocks) {
-    rocks.rotate_left();
+    *rocks = rocks.rotate_ccw();
     for _ in 0..4 {
         do_west_shift(rocks);
-        rocks.rotate_right();
+        *rocks = rocks.rotate_cw();
     }
 
-    rocks.rotate_right();
+    *rocks = rocks.rotate_cw();
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, _params: &Params) -> Option<usize> {
     let rocks = parse_rocks(input);
     Some(
         rocks
@@ -74,7 +63,7 @@ pub fn part_one(input: &str) -> Option<usize> {
                 for (i, r) in col.enumerate() {
                     match r {
                         Some(Round) => {
-                            load += rocks.size().0 - free_idx;
+                            load += rocks.rows() - free_idx;
                             free_idx += 1;
                         }
                         Some(Cube) => {
@@ -94,15 +83,15 @@ fn calc_load(rocks: &Rocks) -> usize {
         .iter_cols()
         .map(|col| {
             col.enumerate()
-                .map(|(i, r)| (rocks.size().0 - i) * (*r == Some(Round)) as usize)
+                .map(|(i, r)| (rocks.rows() - i) * (*r == Some(Round)) as usize)
                 .sum::<usize>()
         })
         .sum()
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
     let mut rocks = parse_rocks(input);
-    let n_cycles = 1_000_000_000;
+    let n_cycles = params.get_or("cycles", 1_000_000_000);
     let mut cache = HashMap::new();
     let mut loads = Vec::new();
     let load = calc_load(&rocks);
@@ -133,13 +122,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
         assert_eq!(result, Some(136));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
         assert_eq!(result, Some(64));
     }
 }
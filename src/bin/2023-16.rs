@@ -0,0 +1,102 @@
+advent_of_code::solution!(2023, 16);
+
+use rayon::prelude::*;
+
+enum Tile {
+    MirrorLF,
+    Mirror7L,
+    SplitterV,
+    SplitterH,
+}
+
+use Tile::*;
+
+use advent_of_code::geom::Dir4::{self, *};
+use advent_of_code::grid::{Grid, Pos};
+
+type Tiles = Grid<Option<Tile>>;
+
+fn parse(input: &str) -> Grid<Option<Tile>> {
+    Grid::parse(input, |c| match c {
+        '.' => None,
+        '|' => Some(SplitterV),
+        '-' => Some(SplitterH),
+        '\\' => Some(Mirror7L),
+        '/' => Some(MirrorLF),
+        _ => panic!(""),
+    })
+}
+
+type PosDir = (Pos, Dir4);
+fn do_step(grid: &Tiles, pos: Pos, dir: Dir4) -> [Option<PosDir>; 2] {
+    let mv = |dir| grid.step(pos, dir).map(|pos| (pos, dir));
+    match grid[pos] {
+        None => [mv(dir), None],
+        Some(SplitterV) if !dir.is_vertical() => [mv(Up), mv(Down)],
+        Some(SplitterH) if dir.is_vertical() => [mv(Left), mv(Right)],
+        Some(SplitterV | SplitterH) => [mv(dir), None],
+        Some(Mirror7L) if dir.is_vertical() => [mv(dir.turn_left()), None],
+        Some(Mirror7L) => [mv(dir.turn_right()), None],
+        Some(MirrorLF) if dir.is_vertical() => [mv(dir.turn_right()), None],
+        Some(MirrorLF) => [mv(dir.turn_left()), None],
+    }
+}
+
+fn solve(grid: &Tiles, pos: Pos, dir: Dir4) -> usize {
+    let mut seen = Grid::<u8>::new(grid.rows(), grid.cols());
+    let mut stack = Vec::new();
+    let bit = |dir: Dir4| 1 << dir as u8;
+    seen[pos] |= bit(dir);
+    stack.push((pos, dir));
+    while let Some((pos, dir)) = stack.pop() {
+        for (pos, dir) in do_step(grid, pos, dir).into_iter().flatten() {
+            if (seen[pos] & bit(dir)) == 0 {
+                seen[pos] |= bit(dir);
+                stack.push((pos, dir));
+            }
+        }
+    }
+
+    seen.iter().filter(|&x| *x != 0).count()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = parse(input);
+    Some(solve(&grid, (0, 0), Right))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let grid = parse(input);
+    let (m, n) = grid.size();
+    let mut starts = Vec::with_capacity(2 * (m + n));
+    for r in 0..m {
+        starts.push(((r, 0), Right));
+        starts.push(((r, n - 1), Left));
+    }
+    for c in 0..n {
+        starts.push(((0, c), Down));
+        starts.push(((m - 1, c), Up));
+    }
+
+    starts
+        .par_iter()
+        .map(|&(pos, dir)| solve(&grid, pos, dir))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(51));
+    }
+}
@@ -0,0 +1,75 @@
+use advent_of_code::geom::Dir4::{self, *};
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::search::{dijkstra_with, DenseIndex};
+use arrayvec::ArrayVec;
+
+advent_of_code::solution!(2023, 17);
+
+type Heats = Grid<usize>;
+
+fn parse(input: &str) -> Heats {
+    Grid::parse(input, |c| c.to_digit(10).unwrap() as usize)
+}
+
+type State = (Pos, Dir4, usize);
+
+fn next(
+    heats: &Heats,
+    (pos, dir, cnt): State,
+    min_cnt: usize,
+    max_cnt: usize,
+) -> ArrayVec<State, 3> {
+    Dir4::ALL
+        .into_iter()
+        .filter(|&d| d != dir.opposite())
+        .filter(|&d| (d != dir && cnt >= min_cnt) || (d == dir && cnt < max_cnt))
+        .filter_map(|d| Some((heats.step(pos, d)?, d, ((d == dir) as usize * cnt) + 1)))
+        .collect()
+}
+
+fn solve(heats: &Heats, min_cnt: usize, max_cnt: usize) -> Option<usize> {
+    let goal = (heats.rows() - 1, heats.cols() - 1);
+    let cols = heats.cols();
+    // every position, direction and count of straight steps has its own slot.
+    let index =
+        |&((r, c), dir, cnt): &State| ((r * cols + c) * 4 + dir as usize) * (max_cnt + 1) + cnt;
+
+    let search = dijkstra_with(
+        DenseIndex(index),
+        [((0, 0), Down, 0), ((0, 0), Right, 0)],
+        |&state| {
+            next(heats, state, min_cnt, max_cnt)
+                .into_iter()
+                .map(|next| (next, heats[next.0]))
+        },
+        |&(pos, _, cnt)| pos == goal && cnt >= min_cnt,
+    );
+    search.goal().map(|(_, cost)| cost)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let heats = parse(input);
+    solve(&heats, 0, 3)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let heats = parse(input);
+    solve(&heats, 4, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(102));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(94));
+    }
+}
@@ -0,0 +1,68 @@
+advent_of_code::solution!(2023, 18);
+
+use advent_of_code::geom::{
+    Dir4::{self, *},
+    Point,
+};
+use advent_of_code::polygon::Polygon;
+use itertools::Itertools;
+
+fn parse_dirs(input: &str) -> Vec<(Dir4, usize)> {
+    input
+        .lines()
+        .map(|l| {
+            let (d, n, _) = l.split_ascii_whitespace().collect_tuple().unwrap();
+            (d.parse().expect("Wrong Dir"), n.parse().unwrap())
+        })
+        .collect()
+}
+
+fn parse_colors(input: &str) -> Vec<(Dir4, usize)> {
+    input
+        .lines()
+        .map(|l| {
+            let (_, _, hex) = l.split_ascii_whitespace().collect_tuple().unwrap();
+            (
+                match hex.as_bytes()[7] {
+                    b'0' => Right,
+                    b'1' => Down,
+                    b'2' => Left,
+                    b'3' => Up,
+                    _ => unreachable!("Wrong Dir"),
+                },
+                usize::from_str_radix(&hex[2..7], 16).unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// The number of tiles of the trench and its inside.
+fn get_volume(dirs: Vec<(Dir4, usize)>) -> usize {
+    let walk = dirs.into_iter().map(|(dir, cnt)| (dir, cnt as isize));
+    Polygon::from_walk(Point::default(), walk).lattice_points() as usize
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(get_volume(parse_dirs(input)))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(get_volume(parse_colors(input)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(952408144115));
+    }
+}
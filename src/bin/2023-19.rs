@@ -1,4 +1,4 @@
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19);
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
 enum Category {
@@ -242,13 +242,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(167409079868000));
     }
 }
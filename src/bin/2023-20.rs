@@ -1,4 +1,4 @@
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 enum Pulse {
@@ -197,17 +197,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(32000000));
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(11687500));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,175 @@
+use advent_of_code::grid::{Grid, Pos, OFFSETS_4};
+use advent_of_code::search::{bfs_with, DenseIndex};
+use advent_of_code::template::params::Params;
+use arrayvec::ArrayVec;
+
+advent_of_code::solution!(2023, 21, params);
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+enum Tile {
+    Start,
+    Rock,
+    Garden,
+}
+
+use itertools::Itertools;
+use Tile::*;
+
+type Tiles = Grid<Tile>;
+
+fn next_moves(tiles: &Tiles, pos: Pos) -> ArrayVec<Pos, 4> {
+    tiles
+        .neighbors4(pos)
+        .filter(|&p| tiles[p] != Rock)
+        .collect()
+}
+
+fn next_moves_inf(tiles: &Tiles, (r, c): (isize, isize)) -> ArrayVec<(isize, isize), 4> {
+    OFFSETS_4
+        .into_iter()
+        .map(|(dr, dc)| (r + dr, c + dc))
+        .filter(|&pos| *tiles.get_wrapping(pos) != Rock)
+        .collect()
+}
+
+fn parse_tiles(input: &str) -> Tiles {
+    Grid::parse(input, |c| match c {
+        'S' => Start,
+        '.' => Garden,
+        '#' => Rock,
+        _ => unreachable!("Unknown Tile"),
+    })
+}
+
+fn get_distances(tiles: &Tiles) -> Vec<usize> {
+    let start = tiles.position(|&t| t == Start).unwrap();
+    let cols = tiles.cols();
+
+    bfs_with(
+        DenseIndex(|&(r, c): &Pos| r * cols + c),
+        [start],
+        |&pos| next_moves(tiles, pos),
+        |_| false,
+    )
+    .iter()
+    .map(|(_, d)| d)
+    .collect()
+}
+
+fn get_distances_inf(tiles: &Tiles, max_dist: usize) -> Vec<usize> {
+    let start = tiles.position(|&t| t == Start).unwrap();
+    let start = (start.0 as isize, start.1 as isize);
+    let rows = tiles.rows();
+    // the explored area spans three tiles in every direction of the start tile.
+    let offset = rows as isize * 3;
+    let index = |&((r, c), _): &((isize, isize), usize)| {
+        (r + offset) as usize * rows * 7 + (c + offset) as usize
+    };
+
+    // the distance is part of the node to stop at `max_dist`, but not of its index.
+    bfs_with(
+        DenseIndex(index),
+        [(start, 0)],
+        |&(pos, d)| {
+            next_moves_inf(tiles, pos)
+                .into_iter()
+                .filter(move |_| d < max_dist)
+                .map(move |pos| (pos, d + 1))
+        },
+        |_| false,
+    )
+    .iter()
+    .map(|(_, d)| d)
+    .collect()
+}
+
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let steps = params.get_or("steps", 64);
+    Some(
+        get_distances(&parse_tiles(input))
+            .into_iter()
+            .filter(|&d| d % 2 == steps % 2 && d <= steps)
+            .count(),
+    )
+}
+
+fn eval_quad(xs: &[usize; 3], ys: &[usize; 3], x: usize) -> usize {
+    let x0 = xs[0];
+    let x1 = xs[1];
+    let x2 = xs[2];
+
+    let y0 = ys[0];
+    let y1 = ys[1];
+    let y2 = ys[2];
+
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+        + (x - x0)
+            * (x - x1)
+            * ((y2 - y1) / ((x2 - x1) * (x2 - x0)) - (y1 - y0) / ((x1 - x0) * (x2 - x0)))
+}
+
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
+    let n = params.get_or("steps", 26501365);
+    let tiles = parse_tiles(input);
+    assert_eq!(tiles.rows(), tiles.cols());
+    let m = tiles.rows();
+
+    // few enough steps to stay within the explored tiles, count them directly.
+    if n <= m * 3 {
+        let dists = get_distances_inf(&tiles, n);
+        return Some(dists.iter().filter(|&&v| v % 2 == n % 2).count());
+    }
+
+    let dists = get_distances_inf(&tiles, m * 2 + m % n);
+    let xs = [0, 1, 2];
+    let ys = xs
+        .iter()
+        .map(|i| (n % m) + i * m)
+        .map(|x| dists.iter().filter(|&&v| v % 2 == x % 2 && v <= x).count())
+        .collect_vec()
+        .try_into()
+        .unwrap();
+    Some(eval_quad(&xs, &ys, n / m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, Some(42));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, Some(394693535848011));
+    }
+
+    #[test]
+    fn test_part_two_steps() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::from([("steps", 10)]),
+        );
+        assert_eq!(result, Some(50));
+    }
+
+    #[test]
+    fn test_part_two_counts_start_once() {
+        // seeding the start at an unreachable cell counted it twice after an even number of steps.
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::from([("steps", 2)]),
+        );
+        assert_eq!(result, Some(4));
+    }
+}
@@ -3,7 +3,7 @@ use ndarray::{s, Array2};
 use std::ops::Range;
 use tuple::Map;
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22);
 
 type Range3 = [Range<usize>; 3];
 
@@ -106,13 +106,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(7));
     }
 }
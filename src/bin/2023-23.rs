@@ -0,0 +1,169 @@
+use std::collections::{HashSet, VecDeque};
+
+use advent_of_code::geom::Dir4;
+use advent_of_code::grid::{Grid, Pos};
+use arrayvec::ArrayVec;
+
+advent_of_code::solution!(2023, 23);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Dir4),
+}
+
+use Tile::*;
+
+type Tiles = Grid<Tile>;
+
+fn parse(input: &str) -> Tiles {
+    Grid::parse(input, |c| match c {
+        '.' => Path,
+        '#' => Forest,
+        c => Slope(Dir4::try_from(c).expect("Unknown Tile")),
+    })
+}
+
+fn parse2(input: &str) -> Tiles {
+    Grid::parse(input, |c| match c {
+        '.' | '<' | 'v' | '>' | '^' => Path,
+        '#' => Forest,
+        _ => unreachable!("Unknown Tile"),
+    })
+}
+
+fn pois(grid: &Tiles) -> Vec<Pos> {
+    grid.indexed_iter()
+        .filter(|&(_, t)| *t != Forest)
+        .filter(|&((r, c), _)| {
+            r == 0
+                || r + 1 == grid.rows()
+                || grid
+                    .neighbors4((r, c))
+                    .filter(|&pos| grid[pos] != Forest)
+                    .count()
+                    > 2
+        })
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn find_neighbors(grid: &Tiles, u: Pos, pois: &[Pos]) -> Vec<(Pos, usize)> {
+    let mut seen = HashSet::new();
+    let mut res = vec![];
+    let mut q = VecDeque::new();
+    q.push_back((u, 0));
+    seen.insert(u);
+    while let Some((v, d)) = q.pop_front() {
+        if v != u && pois.contains(&v) {
+            res.push((v, d));
+        } else {
+            q.extend(
+                grid.neighbors4(v)
+                    .filter(|&pos| grid[pos] != Forest && seen.insert(pos))
+                    .map(|pos| (pos, d + 1)),
+            );
+        }
+    }
+
+    res
+}
+
+fn dfs_impl(grid: &Tiles, pos: Pos, visited: &mut Grid<bool>) -> Option<usize> {
+    if pos.0 + 1 == grid.rows() && grid[pos] == Path {
+        // println!("Found Path of Len {}", path.len() - 1);
+        return Some(0);
+    }
+
+    let mut next = ArrayVec::<Pos, 4>::new();
+    match grid[pos] {
+        Path => next.extend(grid.neighbors4(pos)),
+        Slope(dir) => next.extend(grid.step(pos, dir)),
+        Forest => panic!("Unexpected Forest"),
+    };
+
+    let filtered = ArrayVec::<Pos, 4>::from_iter(
+        next.into_iter()
+            .filter(|&n| grid[n] != Forest && !visited[n]),
+    );
+
+    filtered.into_iter().fold(None, |acc, v| {
+        visited[v] = true;
+        let res = dfs_impl(grid, v, visited).map(|res| res + 1);
+        visited[v] = false;
+        if res.is_none() {
+            acc
+        } else {
+            res.map(|l| l.max(acc.unwrap_or(usize::MIN)))
+        }
+    })
+}
+
+fn dfs(grid: &Tiles) -> Option<usize> {
+    let start = (0, grid.row(0).iter().position(|&t| t == Path).unwrap());
+    let mut visited = Grid::new(grid.rows(), grid.cols());
+    visited[start] = true;
+    dfs_impl(grid, start, &mut visited)
+}
+
+type Graph = Vec<ArrayVec<(usize, usize), 4>>;
+
+fn dfs_compressed_impl(g: &Graph, u: usize, seen: u64, end: usize) -> Option<usize> {
+    if u == end {
+        return Some(0);
+    }
+
+    let mut cost = None;
+    for (v, d) in g[u].iter() {
+        if seen & (1 << v) == 0 {
+            if let Some(res) = dfs_compressed_impl(g, *v, seen | (1 << v), end) {
+                cost = Some(cost.unwrap_or(0).max(d + res));
+            }
+        }
+    }
+
+    cost
+}
+
+fn dfs_compressed(g: &Graph, start: usize, end: usize) -> Option<usize> {
+    dfs_compressed_impl(g, start, 1 << start, end)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = parse(input);
+    dfs(&grid)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let grid = parse2(input);
+    let pois = pois(&grid);
+    let compressed: Graph = pois
+        .iter()
+        .map(|p| {
+            ArrayVec::<(usize, usize), 4>::from_iter(
+                find_neighbors(&grid, *p, &pois)
+                    .into_iter()
+                    .map(|(v, d)| (pois.iter().position(|p| *p == v).unwrap(), d)),
+            )
+        })
+        .collect();
+    dfs_compressed(&compressed, 0, pois.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(94));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(154));
+    }
+}
@@ -1,6 +1,7 @@
+use advent_of_code::template::params::Params;
 use itertools::Itertools;
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2023, 24, params);
 
 #[derive(Default, Clone, Debug)]
 struct Line {
@@ -54,9 +55,9 @@ fn parse(line: &str) -> Line {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let min = 200_000_000_000_000.;
-    let max = 400_000_000_000_000.;
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let min = params.get_or("min", 200_000_000_000_000.);
+    let max = params.get_or("max", 400_000_000_000_000.);
     Some(
         input
             .lines()
@@ -104,7 +105,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 //     Line::default()
 // }
 
-pub fn part_two(_input: &str) -> Option<usize> {
+pub fn part_two(_input: &str, _params: &Params) -> Option<usize> {
     // let lines = input.lines().map(parse).collect_vec();
     // l1 = 19, 13, 30 @ -2,  1, -2
     // l2 = 18, 19, 22 @ -1, -1, -2
@@ -133,13 +134,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::from([("min", 7), ("max", 27)]),
+        );
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use advent_of_code::graph::cut_of_size;
+use advent_of_code::template::params::Params;
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 25, params);
+
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let edges = input
+        .lines()
+        .flat_map(|l| {
+            let (u, vs) = l.split_once(": ").unwrap();
+            vs.split_ascii_whitespace().map(|v| (u, v)).collect_vec()
+        })
+        .collect_vec();
+
+    let vertices: HashMap<_, _> = edges
+        .iter()
+        .flat_map(|(u, v)| [*u, *v])
+        .unique()
+        .enumerate()
+        .map(|(i, v)| (v, i))
+        .collect();
+
+    let edges = edges
+        .into_iter()
+        .map(|(u, v)| (*vertices.get(u).unwrap(), *vertices.get(v).unwrap()))
+        .collect_vec();
+
+    let cut = cut_of_size(vertices.len(), &edges, params.get_or("cut", 3))?;
+    let (left, right) = cut.sizes();
+    Some(left * right)
+}
+
+pub fn part_two(_input: &str, _params: &Params) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, Some(54));
+    }
+
+    #[test]
+    fn test_part_one_cut() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::from([("cut", 2)]),
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, None);
+    }
+}
@@ -80,6 +80,56 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// Parses a selection of days, i.e. a comma separated list of days and ranges like `10..=17`, `10..18` or `20..`.
+/// The days are returned in order and without duplicates.
+///
+/// ```
+/// # use advent_of_code::parse_days;
+/// let days = parse_days("3,10..=12,24..").unwrap();
+/// let days: Vec<u8> = days.into_iter().map(|day| day.into_inner()).collect();
+/// assert_eq!(days, [3, 10, 11, 12, 24, 25]);
+///
+/// assert!(parse_days("12..=10").is_err());
+/// assert!(parse_days("0..3").is_err());
+/// ```
+pub fn parse_days(s: &str) -> Result<Vec<Day>, DayFromStrError> {
+    let mut days = vec![];
+
+    for item in s.split(',').map(str::trim) {
+        let Some((start, end)) = item.split_once("..") else {
+            days.push(item.parse()?);
+            continue;
+        };
+
+        let start: Day = if start.is_empty() {
+            Day(1)
+        } else {
+            start.parse()?
+        };
+        let end: Day = match end.strip_prefix('=') {
+            Some(end) => end.parse()?,
+            None if end.is_empty() => Day(25),
+            None => end
+                .parse::<u8>()
+                .ok()
+                .and_then(|end| end.checked_sub(1))
+                .and_then(Day::new)
+                .ok_or(DayFromStrError)?,
+        };
+
+        if start > end {
+            return Err(DayFromStrError);
+        }
+        days.extend((start.0..=end.0).map(Day));
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -114,6 +164,144 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// The year of the first advent of code event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an integer starting from 2015).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the default [`Year`] from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialOrd<u16> for Year {
+    fn partial_cmp(&self, other: &u16) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year starting from {FIRST_YEAR}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a single puzzle by its [`Year`] and [`Day`].
+///
+/// # Display
+/// This value displays as `{year}-{day}`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        Ok(Self {
+            year: year.parse().map_err(|_| PuzzleIdFromStrError)?,
+            day: day.parse().map_err(|_| PuzzleIdFromStrError)?,
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the format `{year}-{day}`, e.g. `2023-01`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -130,11 +318,27 @@ macro_rules! day {
     }};
 }
 
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::FIRST_YEAR && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value starting from 2015"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, PuzzleId, Year};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +371,22 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parse_year() {
+        assert_eq!("2015".parse::<Year>().ok(), Some(Year(2015)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn puzzle_id_roundtrip() {
+        let puzzle: PuzzleId = "2023-1".parse().unwrap();
+        assert_eq!(puzzle, PuzzleId::new(Year(2023), Day(1)));
+        assert_eq!(puzzle.to_string(), "2023-01");
+        assert!("2023".parse::<PuzzleId>().is_err());
+        assert!("2023-26".parse::<PuzzleId>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
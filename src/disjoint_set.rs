@@ -0,0 +1,139 @@
+/// A union-find over the elements `0..n`, with path compression and union by size.
+///
+/// ```
+/// # use advent_of_code::disjoint_set::DisjointSet;
+/// let mut set = DisjointSet::new(4);
+/// assert!(set.union(0, 1));
+/// assert!(!set.union(1, 0));
+/// assert_eq!(set.size(1), 2);
+/// assert_eq!(set.count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Creates `n` components of one element each.
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// The number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the component of `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // points everything on the way directly at the root.
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the components of `x` and `y`, returns whether they were separate.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+
+        let (small, large) = if self.size[x] < self.size[y] {
+            (x, y)
+        } else {
+            (y, x)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.count -= 1;
+        true
+    }
+
+    /// Whether `x` and `y` are in the same component.
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// The number of elements in the component of `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The number of components.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The elements of every component, in the order of their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = components.len();
+                components.push(vec![]);
+            }
+            components[index[root]].push(x);
+        }
+        components
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DisjointSet;
+
+    #[test]
+    fn merges_components() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.len(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.same(0, 3));
+        assert!(!set.same(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+        assert_eq!(set.count(), 3);
+        assert_eq!(set.components(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn compresses_long_chains() {
+        let n = 100_000;
+        let mut set = DisjointSet::new(n);
+        for i in 1..n {
+            set.union(i - 1, i);
+        }
+        assert_eq!(set.count(), 1);
+        assert_eq!(set.size(0), n);
+        let root = set.find(n - 1);
+        assert!((0..n).all(|x| set.find(x) == root));
+    }
+}
@@ -0,0 +1,351 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use num::traits::{CheckedAdd, CheckedSub, One};
+
+use crate::grid::Pos;
+
+/// One of the four directions on a grid, in clockwise order.
+///
+/// Parses from `U`/`D`/`L`/`R`, `N`/`S`/`W`/`E` and `^`/`v`/`<`/`>`:
+///
+/// ```
+/// # use advent_of_code::geom::Dir4;
+/// let dir: Dir4 = "R".parse().unwrap();
+/// assert_eq!(dir, Dir4::try_from('>').unwrap());
+/// assert_eq!(dir.turn_right(), Dir4::Down);
+/// assert_eq!(dir.opposite(), Dir4::Left);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting with [`Dir4::Up`].
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Whether the direction is [`Dir4::Up`] or [`Dir4::Down`].
+    #[must_use]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = DirFromStrError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Dir4::Up),
+            'R' | 'E' | '>' => Ok(Dir4::Right),
+            'D' | 'S' | 'v' => Ok(Dir4::Down),
+            'L' | 'W' | '<' => Ok(Dir4::Left),
+            _ => Err(DirFromStrError),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = DirFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c),
+            _ => Err(DirFromStrError),
+        }
+    }
+}
+
+/// One of the eight directions on a grid including diagonals, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting with [`Dir8::Up`].
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns by 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The change of `x` and `y` by a step in this direction, with `y` growing downwards.
+    #[must_use]
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+/// Parses a [`Dir4`], or a diagonal like `NE` or `UR`.
+impl FromStr for Dir8 {
+    type Err = DirFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" | "UR" => Ok(Dir8::UpRight),
+            "SE" | "DR" => Ok(Dir8::DownRight),
+            "SW" | "DL" => Ok(Dir8::DownLeft),
+            "NW" | "UL" => Ok(Dir8::UpLeft),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Dir4`] or [`Dir8`].
+#[derive(Debug)]
+pub struct DirFromStrError;
+
+impl Error for DirFromStrError {}
+
+impl Display for DirFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a direction like `U`, `N` or `^`")
+    }
+}
+
+/// A point on a grid, with `y` growing downwards. Steps in a direction are unchecked, or checked for overflow with the
+/// `checked_` variants, e.g. to stay within the bounds of `usize` coordinates:
+///
+/// ```
+/// # use advent_of_code::geom::{Dir4, Point};
+/// let point = Point::new(2_usize, 0);
+/// assert_eq!(point.step(Dir4::Down), Point::new(2, 1));
+/// assert_eq!(point.checked_step(Dir4::Up), None);
+/// assert_eq!(point.manhattan(Point::new(0, 3)), 5);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    #[must_use]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd> Point<T> {
+    /// The Manhattan distance, i.e. the number of 4-connected steps between two points.
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        let diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        diff(self.x, other.x) + diff(self.y, other.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + One> Point<T> {
+    /// Moves one step in a direction.
+    #[must_use]
+    pub fn step(self, dir: impl Into<Dir8>) -> Self {
+        self.step_n(dir, T::one())
+    }
+
+    /// Moves `n` steps in a direction.
+    #[must_use]
+    pub fn step_n(self, dir: impl Into<Dir8>, n: T) -> Self {
+        let shift = |v: T, d: i8| match d {
+            -1 => v - n,
+            1 => v + n,
+            _ => v,
+        };
+        let (dx, dy) = dir.into().delta();
+        Self::new(shift(self.x, dx), shift(self.y, dy))
+    }
+}
+
+impl<T: Copy + CheckedAdd + CheckedSub + One> Point<T> {
+    /// Moves one step in a direction, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_step(self, dir: impl Into<Dir8>) -> Option<Self> {
+        self.checked_step_n(dir, T::one())
+    }
+
+    /// Moves `n` steps in a direction, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_step_n(self, dir: impl Into<Dir8>, n: T) -> Option<Self> {
+        let shift = |v: T, d: i8| match d {
+            -1 => v.checked_sub(&n),
+            1 => v.checked_add(&n),
+            _ => Some(v),
+        };
+        let (dx, dy) = dir.into().delta();
+        Some(Self::new(shift(self.x, dx)?, shift(self.y, dy)?))
+    }
+
+    /// Adds two points, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+        ))
+    }
+
+    /// Subtracts two points, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Converts a `(row, column)` position of a [`crate::grid::Grid`].
+impl From<Pos> for Point<usize> {
+    fn from((row, col): Pos) -> Self {
+        Self::new(col, row)
+    }
+}
+
+/// Converts to a `(row, column)` position of a [`crate::grid::Grid`].
+impl From<Point<usize>> for Pos {
+    fn from(point: Point<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Dir4, Dir8, Point};
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Down.opposite(), Dir4::Up);
+        assert!(Dir4::ALL
+            .iter()
+            .all(|&dir| dir.turn_left().turn_left() == dir.opposite()));
+
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parse = |s: &str| s.chars().map(Dir4::try_from).collect::<Result<Vec<_>, _>>();
+        let expected = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+        assert_eq!(parse("UDLR").unwrap(), expected);
+        assert_eq!(parse("NSWE").unwrap(), expected);
+        assert_eq!(parse("^v<>").unwrap(), expected);
+        assert!(parse("x").is_err());
+        assert!("UD".parse::<Dir4>().is_err());
+
+        assert_eq!("NE".parse::<Dir8>().unwrap(), Dir8::UpRight);
+        assert_eq!("<".parse::<Dir8>().unwrap(), Dir8::Left);
+    }
+
+    #[test]
+    fn steps_points() {
+        let point = Point::new(3_isize, -2);
+        assert_eq!(point.step(Dir4::Up), Point::new(3, -3));
+        assert_eq!(point.step(Dir8::DownLeft), Point::new(2, -1));
+        assert_eq!(point.step_n(Dir4::Right, 5), Point::new(8, -2));
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(point * 2, Point::new(6, -4));
+        assert_eq!(point.manhattan(Point::new(0, 0)), 5);
+
+        let corner = Point::new(0_usize, 0);
+        assert_eq!(corner.checked_step(Dir4::Left), None);
+        assert_eq!(corner.checked_step(Dir8::DownRight), Some(Point::new(1, 1)));
+        assert_eq!(corner.checked_sub(Point::new(0, 1)), None);
+        assert_eq!(Point::from((4, 7)), Point::new(7, 4));
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::disjoint_set::DisjointSet;
+
+/// A cut of a graph with vertices `0..n`, i.e. a split of the vertices into two sides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// The total weight of the edges between the sides, their number for unweighted graphs.
+    pub weight: usize,
+    /// Whether each vertex is on the first side.
+    pub side: Vec<bool>,
+}
+
+impl Cut {
+    /// The number of vertices on the first and the second side.
+    #[must_use]
+    pub fn sizes(&self) -> (usize, usize) {
+        let first = self.side.iter().filter(|&&s| s).count();
+        (first, self.side.len() - first)
+    }
+}
+
+/// Finds a minimum cut of an undirected graph with vertices `0..n` and weighted edges `(u, v, weight)` with the
+/// Stoer–Wagner algorithm. Returns `None` for graphs with fewer than two vertices.
+///
+/// Takes `n - 1` phases, each of which adds the vertices in order of their connection to the vertices added before,
+/// and then merges the last two. The lightest cut between the last vertex and the rest over all phases is minimal.
+pub fn stoer_wagner(
+    n: usize,
+    edges: impl IntoIterator<Item = (usize, usize, usize)>,
+) -> Option<Cut> {
+    if n < 2 {
+        return None;
+    }
+
+    let mut adjacent: Vec<HashMap<usize, usize>> = vec![HashMap::new(); n];
+    for (u, v, weight) in edges {
+        if u != v {
+            *adjacent[u].entry(v).or_default() += weight;
+            *adjacent[v].entry(u).or_default() += weight;
+        }
+    }
+
+    let mut merged = DisjointSet::new(n);
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<Cut> = None;
+
+    while active.len() > 1 {
+        let mut connection = vec![0; n];
+        let mut added = vec![false; n];
+        let mut heap: BinaryHeap<_> = active.iter().map(|&v| (0, v)).collect();
+        let (mut prev, mut last, mut cut_weight) = (usize::MAX, usize::MAX, 0);
+
+        while let Some((weight, v)) = heap.pop() {
+            if added[v] || weight != connection[v] {
+                continue;
+            }
+            added[v] = true;
+            (prev, last, cut_weight) = (last, v, weight);
+            for (&u, &w) in &adjacent[v] {
+                if !added[u] {
+                    connection[u] += w;
+                    heap.push((connection[u], u));
+                }
+            }
+        }
+
+        if best.as_ref().is_none_or(|best| cut_weight < best.weight) {
+            let side = (0..n).map(|v| merged.same(v, last)).collect();
+            best = Some(Cut {
+                weight: cut_weight,
+                side,
+            });
+        }
+
+        // merges `last` into `prev`.
+        merged.union(prev, last);
+        active.retain(|&v| v != last);
+        for (u, w) in std::mem::take(&mut adjacent[last]) {
+            adjacent[u].remove(&last);
+            if u != prev {
+                *adjacent[prev].entry(u).or_default() += w;
+                *adjacent[u].entry(prev).or_default() += w;
+            }
+        }
+    }
+
+    best
+}
+
+#[derive(Debug, Clone)]
+struct FlowEdge {
+    to: usize,
+    capacity: usize,
+    flow: usize,
+}
+
+/// A flow network over the vertices `0..n`, whose maximum flow is found with the Edmonds–Karp algorithm.
+///
+/// ```
+/// # use advent_of_code::graph::FlowNetwork;
+/// let mut network = FlowNetwork::new(4);
+/// network.add_edge(0, 1, 3);
+/// network.add_edge(0, 2, 2);
+/// network.add_edge(1, 3, 2);
+/// network.add_edge(2, 3, 3);
+/// assert_eq!(network.max_flow(0, 3, usize::MAX), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlowNetwork {
+    adjacent: Vec<Vec<usize>>,
+    // every edge is followed by its residual edge, so the residual of edge `i` is `i ^ 1`.
+    edges: Vec<FlowEdge>,
+}
+
+impl FlowNetwork {
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self {
+            adjacent: vec![vec![]; n],
+            edges: vec![],
+        }
+    }
+
+    fn add(&mut self, u: usize, v: usize, forward: usize, backward: usize) {
+        self.adjacent[u].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            to: v,
+            capacity: forward,
+            flow: 0,
+        });
+        self.adjacent[v].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            to: u,
+            capacity: backward,
+            flow: 0,
+        });
+    }
+
+    /// Adds an edge from `u` to `v`.
+    pub fn add_edge(&mut self, u: usize, v: usize, capacity: usize) {
+        self.add(u, v, capacity, 0);
+    }
+
+    /// Adds an edge that can be used in both directions, e.g. of an undirected graph.
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, capacity: usize) {
+        self.add(u, v, capacity, capacity);
+    }
+
+    /// Removes all flow, e.g. to find the flow between other vertices.
+    pub fn reset(&mut self) {
+        for edge in &mut self.edges {
+            edge.flow = 0;
+        }
+    }
+
+    fn residual(&self, edge: usize) -> usize {
+        // an undirected edge is used in reverse by cancelling the flow of its residual edge first.
+        self.edges[edge].capacity + self.edges[edge ^ 1].flow - self.edges[edge].flow
+    }
+
+    /// Finds the edges of a shortest path from `source` to `sink` with residual capacity left.
+    fn augmenting_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut via = vec![usize::MAX; self.adjacent.len()];
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            if u == sink {
+                let mut path = vec![];
+                let mut v = sink;
+                while v != source {
+                    path.push(via[v]);
+                    v = self.edges[via[v] ^ 1].to;
+                }
+                return Some(path);
+            }
+            for &edge in &self.adjacent[u] {
+                let v = self.edges[edge].to;
+                if v != source && via[v] == usize::MAX && self.residual(edge) > 0 {
+                    via[v] = edge;
+                    queue.push_back(v);
+                }
+            }
+        }
+        None
+    }
+
+    /// Sends as much flow as possible from `source` to `sink`, but stops once it reaches `limit`, and returns it.
+    /// For unit capacities, this is the number of edge-disjoint paths between them.
+    pub fn max_flow(&mut self, source: usize, sink: usize, limit: usize) -> usize {
+        let mut total = 0;
+        while total < limit {
+            let Some(path) = self.augmenting_path(source, sink) else {
+                break;
+            };
+            let amount = path
+                .iter()
+                .map(|&edge| self.residual(edge))
+                .min()
+                .unwrap()
+                .min(limit - total);
+            for &edge in &path {
+                // cancels flow in the opposite direction before adding to this one.
+                let cancelled = amount.min(self.edges[edge ^ 1].flow);
+                self.edges[edge ^ 1].flow -= cancelled;
+                self.edges[edge].flow += amount - cancelled;
+            }
+            total += amount;
+        }
+        total
+    }
+
+    /// The vertices that can still be reached from `source` after [`FlowNetwork::max_flow`]. The edges leaving them
+    /// form a minimum cut between `source` and the sink.
+    #[must_use]
+    pub fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.adjacent.len()];
+        reached[source] = true;
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            for &edge in &self.adjacent[u] {
+                let v = self.edges[edge].to;
+                if !reached[v] && self.residual(edge) > 0 {
+                    reached[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        reached
+    }
+}
+
+/// Finds a cut of at most `size` edges in an undirected, unweighted graph with vertices `0..n`, e.g. when the size of
+/// the minimum cut is known.
+///
+/// Tries the other vertices as sinks for vertex `0`, and looks for `size + 1` edge-disjoint paths to each. Any vertex
+/// on the other side of a small enough cut has fewer.
+#[must_use]
+pub fn cut_of_size(n: usize, edges: &[(usize, usize)], size: usize) -> Option<Cut> {
+    let mut network = FlowNetwork::new(n);
+    for &(u, v) in edges {
+        network.add_undirected_edge(u, v, 1);
+    }
+
+    (1..n).find_map(|sink| {
+        network.reset();
+        let weight = network.max_flow(0, sink, size + 1);
+        (weight <= size).then(|| Cut {
+            weight,
+            side: network.reachable(0),
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{cut_of_size, stoer_wagner, FlowNetwork};
+
+    /// Two cliques of four vertices, joined by the edges `3-4` and `0-7`.
+    fn get_mock_edges() -> Vec<(usize, usize)> {
+        let mut edges = vec![(3, 4), (0, 7)];
+        for offset in [0, 4] {
+            for u in 0..4 {
+                for v in u + 1..4 {
+                    edges.push((u + offset, v + offset));
+                }
+            }
+        }
+        edges
+    }
+
+    #[test]
+    fn finds_minimum_cuts() {
+        let edges = get_mock_edges();
+        let cut = stoer_wagner(8, edges.iter().map(|&(u, v)| (u, v, 1))).unwrap();
+        assert_eq!(cut.weight, 2);
+        assert_eq!(cut.sizes(), (4, 4));
+        assert!((0..4).all(|v| cut.side[v] == cut.side[0]));
+
+        // a heavier bridge moves the minimum cut to a single vertex.
+        let weighted = edges
+            .iter()
+            .map(|&(u, v)| (u, v, if (u, v) == (3, 4) { 5 } else { 1 }));
+        assert_eq!(stoer_wagner(8, weighted).unwrap().weight, 3);
+
+        assert_eq!(stoer_wagner(1, []), None);
+        assert_eq!(stoer_wagner(3, [(0, 1, 1)]).unwrap().weight, 0);
+    }
+
+    #[test]
+    fn finds_maximum_flows() {
+        let mut network = FlowNetwork::new(6);
+        for (u, v, capacity) in [
+            (0, 1, 10),
+            (0, 2, 10),
+            (1, 2, 2),
+            (1, 3, 4),
+            (1, 4, 8),
+            (2, 4, 9),
+            (3, 5, 10),
+        ] {
+            network.add_edge(u, v, capacity);
+        }
+        network.add_edge(4, 3, 6);
+        network.add_edge(4, 5, 10);
+        assert_eq!(network.max_flow(0, 5, usize::MAX), 19);
+        // the edges `0-1` and `2-4` are saturated.
+        assert_eq!(
+            network.reachable(0),
+            [true, false, true, false, false, false]
+        );
+
+        network.reset();
+        assert_eq!(network.max_flow(0, 5, 7), 7);
+    }
+
+    #[test]
+    fn finds_cuts_of_size() {
+        let edges = get_mock_edges();
+        assert_eq!(cut_of_size(8, &edges, 1), None);
+
+        let cut = cut_of_size(8, &edges, 2).unwrap();
+        assert_eq!(cut.weight, 2);
+        assert_eq!(cut.sizes(), (4, 4));
+        assert!(cut.side[0] && !cut.side[4]);
+    }
+}
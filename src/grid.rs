@@ -0,0 +1,382 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::geom::{Dir8, Point};
+
+/// A position in a [`Grid`] as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4-connected neighbors of a cell: up, down, left and right.
+pub const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8-connected neighbors of a cell, row by row.
+pub const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Puzzle inputs are parsed with a mapping from characters to cells, and [`Display`] turns the grid back into text:
+///
+/// ```
+/// # use advent_of_code::grid::Grid;
+/// let grid = Grid::parse("#.\n.#", |c| c == '#');
+/// assert_eq!(grid.size(), (2, 2));
+/// assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+/// assert_eq!(grid.map(|&rock| if rock { '#' } else { '.' }).to_string(), "#.\n.#");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not `rows * cols`.
+    #[must_use]
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * cols, "expected {rows}x{cols} cells");
+        Self { rows, cols, cells }
+    }
+
+    /// Parses a grid with one row per line, mapping every character to a cell.
+    ///
+    /// # Panics
+    ///
+    /// If the lines have different lengths.
+    #[must_use]
+    pub fn parse(input: &str, f: impl FnMut(char) -> T) -> Self {
+        let rows = input.lines().count();
+        let cols = input.lines().next().map_or(0, |l| l.chars().count());
+        assert!(
+            input.lines().all(|l| l.chars().count() == cols),
+            "rows of a grid must have the same length"
+        );
+        Self::from_vec(
+            rows,
+            cols,
+            input.lines().flat_map(str::chars).map(f).collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `(rows, cols)`.
+    #[must_use]
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    #[must_use]
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at a position of the grid repeated infinitely in every direction.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    #[must_use]
+    pub fn get_wrapping(&self, pos: (isize, isize)) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// Maps a position of the grid repeated infinitely in every direction to the position in this grid.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn wrap(&self, (r, c): (isize, isize)) -> Pos {
+        (
+            r.rem_euclid(self.rows as isize) as usize,
+            c.rem_euclid(self.cols as isize) as usize,
+        )
+    }
+
+    /// Moves a position by an offset, returning `None` if it leaves the grid.
+    #[must_use]
+    pub fn offset(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Moves a position one step in a direction, returning `None` if it leaves the grid.
+    #[must_use]
+    pub fn step(&self, pos: Pos, dir: impl Into<Dir8>) -> Option<Pos> {
+        let pos = Point::from(pos).checked_step(dir)?.into();
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The 4-connected neighbors of a position that lie in the grid, in the order of [`OFFSETS_4`].
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// The 8-connected neighbors of a position that lie in the grid, in the order of [`OFFSETS_8`].
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// The cells of a row.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    #[must_use]
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// The cells of a row.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    #[must_use]
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// The cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    pub fn col(&self, c: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(c < self.cols, "column {c} out of bounds");
+        self.cells[c..].iter().step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.rows).map(|r| self.row(r))
+    }
+
+    pub fn iter_cols(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|c| self.col(c))
+    }
+
+    /// The cells row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells row by row, together with their position.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| (i / cols, i % cols))
+    }
+
+    /// The position of the first cell, row by row, that matches a predicate.
+    #[must_use]
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.cols, i % self.cols))
+    }
+
+    /// Creates a grid of the same size with every cell mapped by `f`.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    #[must_use]
+    pub fn from_elem(rows: usize, cols: usize, value: T) -> Self {
+        Self::from_vec(rows, cols, vec![value; rows * cols])
+    }
+
+    /// Mirrors the grid along its main diagonal, i.e. rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.cols, self.rows, |(r, c)| (c, r))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(self.cols, self.rows, |(r, c)| (self.rows - 1 - c, r))
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(self.cols, self.rows, |(r, c)| (c, self.cols - 1 - r))
+    }
+
+    /// Creates a `rows` x `cols` grid that takes each cell from the position `source` maps it to.
+    fn remap(&self, rows: usize, cols: usize, source: impl Fn(Pos) -> Pos) -> Self {
+        let cells = (0..rows * cols)
+            .map(|i| self[source((i / cols, i % cols))].clone())
+            .collect();
+        Self::from_vec(rows, cols, cells)
+    }
+}
+
+impl<T: Clone + Default> Grid<T> {
+    /// Creates a grid with every cell set to its default value.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::from_elem(rows, cols, T::default())
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} out of bounds of {}x{} grid",
+                self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = self.size();
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} out of bounds of {rows}x{cols} grid"))
+    }
+}
+
+/// Writes one line per row, e.g. a `Grid<char>` displays as the text it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (r, row) in self.iter_rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::geom::{Dir4, Dir8};
+
+    fn get_mock_grid() -> Grid<char> {
+        Grid::parse("abc\ndef", |c| c)
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'f'), Some((1, 2)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn rejects_ragged_rows() {
+        let _ = Grid::parse("ab\nc", |c| c);
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = get_mock_grid();
+        assert_eq!(
+            grid.neighbors4((0, 1)).collect::<Vec<_>>(),
+            [(1, 1), (0, 0), (0, 2)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 0)).collect::<Vec<_>>(),
+            [(0, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+        assert_eq!(grid.step((1, 2), Dir4::Up), Some((0, 2)));
+        assert_eq!(grid.step((1, 2), Dir8::DownLeft), None);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let mut grid = get_mock_grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.col(1).collect::<String>(), "be");
+        assert_eq!(grid.col(2).rev().collect::<String>(), "fc");
+        assert_eq!(
+            grid.iter_cols()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        grid.row_mut(0)[0] = 'x';
+        assert_eq!(grid.to_string(), "xbc\ndef");
+    }
+
+    #[test]
+    fn wraps_positions() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.wrap((-1, -1)), (1, 2));
+        assert_eq!(*grid.get_wrapping((2, 4)), 'b');
+        assert_eq!(*grid.get_wrapping((-3, 3)), 'd');
+    }
+}
@@ -1,4 +1,10 @@
 mod day;
+pub mod disjoint_set;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod polygon;
+pub mod search;
 pub mod template;
 
 pub use day::*;
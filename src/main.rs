@@ -1,55 +1,225 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, compare, download, examples, read, scaffold, solve, verify,
+};
 use args::{parse, AppArguments};
 
+/// The solution registry, generated by `build.rs` from the binaries in `src/bin`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use std::time::Duration;
+
+    use advent_of_code::template::{
+        commands::{all, scaffold},
+        inputs::Source,
+        params::Params,
+        runner::{self, BenchConfig, RunOptions},
+    };
+    use advent_of_code::{parse_days, Day, PuzzleId, Year};
+    use itertools::Itertools;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            options: scaffold::Options,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
-            time: bool,
-            submit: Option<u8>,
+            watch: bool,
+            options: RunOptions,
+        },
+        Compare {
+            baseline: Option<String>,
+            current: Option<String>,
+            threshold: f64,
+        },
+        Verify {
+            year: Option<Year>,
+            timeout: Option<Duration>,
+            jobs: usize,
         },
         All {
-            release: bool,
-            time: bool,
+            options: RunOptions,
+            jobs: usize,
+            selection: all::Selection,
         },
     }
 
+    /// Parses the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified, pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Parses a puzzle from the `--year` option and the day passed as free argument.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        Ok(PuzzleId::new(year, args.free_from_str()?))
+    }
+
+    /// Parses the `--warm-up` and `--bench-time` options (in milliseconds) used by `--time`.
+    fn parse_bench_config(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let defaults = BenchConfig::default();
+        Ok(BenchConfig {
+            warm_up: args
+                .opt_value_from_str("--warm-up")?
+                .map_or(defaults.warm_up, Duration::from_millis),
+            time_budget: args
+                .opt_value_from_str("--bench-time")?
+                .map_or(defaults.time_budget, Duration::from_millis),
+        })
+    }
+
+    /// Parses the `--timeout` option (in milliseconds).
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        Ok(args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_millis))
+    }
+
+    /// Parses the `--jobs` option, the number of days that run in parallel.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--jobs")? {
+            Some(0) => Err("--jobs must be at least 1.".into()),
+            Some(jobs) => Ok(jobs),
+            None => Ok(1),
+        }
+    }
+
+    /// Parses the days to run, given as free argument like `10..=17` and/or as `--days 3,8,21`, and the
+    /// `--year`, `--slowest` and `--only-unsolved` options. Must be called after all other options.
+    fn parse_selection(
+        args: &mut pico_args::Arguments,
+    ) -> Result<all::Selection, Box<dyn std::error::Error>> {
+        let year = args.opt_value_from_str("--year")?;
+        let slowest = args.opt_value_from_str("--slowest")?;
+        let only_unsolved = args.contains("--only-unsolved");
+        let listed: Option<Vec<Day>> = args.opt_value_from_fn("--days", parse_days)?;
+        let range: Option<Vec<Day>> = args.opt_free_from_fn(parse_days)?;
+
+        let days = match (listed, range) {
+            (Some(listed), Some(range)) => {
+                Some(listed.into_iter().chain(range).sorted().dedup().collect())
+            }
+            (listed, range) => listed.or(range),
+        };
+
+        Ok(all::Selection {
+            year,
+            days,
+            slowest,
+            only_unsolved,
+        })
+    }
+
+    /// Parses the `--input <path|->` and `--example [name]` options. Must be called after all other options,
+    /// as the name of the example is the remaining free argument.
+    fn parse_source(args: &mut pico_args::Arguments) -> Result<Source, Box<dyn std::error::Error>> {
+        if let Some(path) = args.opt_value_from_str::<_, String>("--input")? {
+            return Ok(if path == "-" {
+                Source::Stdin
+            } else {
+                Source::File(path.into())
+            });
+        }
+
+        if args.contains("--example") {
+            return Ok(Source::Example(args.opt_free_from_str()?));
+        }
+
+        Ok(Source::Puzzle)
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+                options: RunOptions {
+                    is_timed: args.contains("--time"),
+                    verify: true,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench: parse_bench_config(&mut args)?,
+                    timeout: parse_timeout(&mut args)?,
+                    mem: args.contains("--mem"),
+                    ..RunOptions::default()
+                },
+                jobs: parse_jobs(&mut args)?,
+                selection: parse_selection(&mut args)?,
+            },
+            Some("compare") => AppArguments::Compare {
+                baseline: args.opt_value_from_str("--baseline")?,
+                current: args.opt_value_from_str("--current")?,
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                options: scaffold::Options {
+                    answer_type: args
+                        .opt_value_from_str("--type")?
+                        .unwrap_or_else(|| scaffold::Options::default().answer_type),
+                    expected: [
+                        args.opt_value_from_str("--expect-one")?,
+                        args.opt_value_from_str("--expect-two")?,
+                    ],
+                },
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                watch: args.contains("--watch"),
+                options: RunOptions {
+                    submit: args.opt_value_from_str("--submit")?,
+                    is_timed: args.contains("--time"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench: parse_bench_config(&mut args)?,
+                    timeout: parse_timeout(&mut args)?,
+                    mem: args.contains("--mem"),
+                    part: args.opt_value_from_fn("--part", runner::parse_part)?,
+                    params: args
+                        .values_from_fn("--param", Params::parse_pair)?
+                        .into_iter()
+                        .collect::<Params>(),
+                    input: parse_source(&mut args)?,
+                    ..RunOptions::default()
+                },
+            },
+            Some("verify") => AppArguments::Verify {
+                year: args.opt_value_from_str("--year")?,
+                timeout: parse_timeout(&mut args)?,
+                jobs: parse_jobs(&mut args)?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +247,31 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                options,
+                jobs,
+                selection,
+            } => all::handle(solutions::SOLUTIONS, &selection, &options, jobs),
+            AppArguments::Compare {
+                baseline,
+                current,
+                threshold,
+            } => compare::handle(baseline, current, threshold),
+            AppArguments::Verify {
+                year,
+                timeout,
+                jobs,
+            } => verify::handle(solutions::SOLUTIONS, year, timeout, jobs),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle, options } => scaffold::handle(puzzle, options),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                watch,
+                options,
+            } => solve::handle(puzzle, release, watch, &options),
         },
     };
 }
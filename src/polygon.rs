@@ -0,0 +1,124 @@
+use num::{Integer, Signed};
+
+use crate::geom::{Dir8, Point};
+
+/// A simple polygon with integer vertices, e.g. a loop on a grid. Edges connect consecutive vertices and the last
+/// vertex with the first one.
+///
+/// Areas are kept exact by doubling them, and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem) counts
+/// the lattice points inside:
+///
+/// ```
+/// # use advent_of_code::geom::{Dir4, Point};
+/// # use advent_of_code::polygon::Polygon;
+/// let square = Polygon::from_walk(Point::new(0, 0), [(Dir4::Right, 2), (Dir4::Down, 2), (Dir4::Left, 2), (Dir4::Up, 2)]);
+/// assert_eq!(square.doubled_area(), 8);
+/// assert_eq!(square.boundary_points(), 8);
+/// assert_eq!(square.interior_points(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point<T>>,
+}
+
+impl<T: Copy + Integer + Signed> Polygon<T> {
+    #[must_use]
+    pub fn new(vertices: Vec<Point<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Walks from `start` by a number of steps in every direction, with a vertex at the end of every part of the walk.
+    /// The walk should end at `start` again, which then is the last vertex.
+    pub fn from_walk<D: Into<Dir8>>(
+        start: Point<T>,
+        walk: impl IntoIterator<Item = (D, T)>,
+    ) -> Self {
+        let vertices = walk
+            .into_iter()
+            .scan(start, |pos, (dir, n)| {
+                *pos = pos.step_n(dir, n);
+                Some(*pos)
+            })
+            .collect();
+        Self::new(vertices)
+    }
+
+    #[must_use]
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area enclosed by the polygon, with the shoelace formula. Twice, as the area of a polygon with integer
+    /// vertices may be a half.
+    #[must_use]
+    pub fn doubled_area(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |acc, (a, b)| acc + a.x * b.y - b.x * a.y)
+            .abs()
+    }
+
+    /// The number of lattice points on the edges, including the vertices.
+    #[must_use]
+    pub fn boundary_points(&self) -> T {
+        self.edges().fold(T::zero(), |acc, (a, b)| {
+            let diff = b - a;
+            acc + diff.x.abs().gcd(&diff.y.abs())
+        })
+    }
+
+    /// The number of lattice points strictly inside the polygon, by Pick's theorem `A = I + B / 2 - 1`.
+    #[must_use]
+    pub fn interior_points(&self) -> T {
+        let two = T::one() + T::one();
+        (self.doubled_area() - self.boundary_points() + two) / two
+    }
+
+    /// The number of lattice points inside or on the polygon, e.g. the tiles covered by a loop and its inside.
+    #[must_use]
+    pub fn lattice_points(&self) -> T {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Polygon;
+    use crate::geom::{Dir4::*, Point};
+
+    #[test]
+    fn measures_polygons() {
+        // a triangle with a half area and points on its hypotenuse.
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)]);
+        assert_eq!(triangle.doubled_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+
+        let reversed = Polygon::new(triangle.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.doubled_area(), 12);
+    }
+
+    #[test]
+    fn walks_polygons() {
+        // a 2x2 square without its top right quarter.
+        let walk = [
+            (Right, 1_i64),
+            (Down, 1),
+            (Right, 1),
+            (Down, 1),
+            (Left, 2),
+            (Up, 2),
+        ];
+        let shape = Polygon::from_walk(Point::new(0, 0), walk);
+        assert_eq!(shape.vertices().len(), 6);
+        assert_eq!(shape.vertices()[5], Point::new(0, 0));
+        assert_eq!(shape.doubled_area(), 6);
+        assert_eq!(shape.boundary_points(), 8);
+        assert_eq!(shape.interior_points(), 0);
+        assert_eq!(shape.lattice_points(), 8);
+    }
+}
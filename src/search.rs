@@ -0,0 +1,413 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use num::traits::Zero;
+
+/// Assigns every node of a search an index, at which its cost and predecessor are stored.
+pub trait Indexer<N> {
+    /// Returns the index of a node, assigning one if the node is new.
+    fn index(&mut self, node: &N) -> usize;
+
+    /// Returns the index of a node, or `None` if it was never indexed.
+    fn get(&self, node: &N) -> Option<usize>;
+}
+
+/// Indexes nodes by hashing them, in the order they are reached. Used by [`bfs`], [`dijkstra`] and [`astar`].
+#[derive(Debug, Clone)]
+pub struct HashIndexer<N>(HashMap<N, usize>);
+
+impl<N> Default for HashIndexer<N> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<N: Clone + Eq + Hash> Indexer<N> for HashIndexer<N> {
+    fn index(&mut self, node: &N) -> usize {
+        if let Some(&index) = self.0.get(node) {
+            return index;
+        }
+        let index = self.0.len();
+        self.0.insert(node.clone(), index);
+        index
+    }
+
+    fn get(&self, node: &N) -> Option<usize> {
+        self.0.get(node).copied()
+    }
+}
+
+/// Indexes nodes with a function that maps every node to its own small integer, e.g. `row * cols + col`.
+/// Avoids hashing, which makes searches over large state spaces considerably faster.
+#[derive(Debug, Clone, Copy)]
+pub struct DenseIndex<F>(pub F);
+
+impl<N, F: Fn(&N) -> usize> Indexer<N> for DenseIndex<F> {
+    fn index(&mut self, node: &N) -> usize {
+        (self.0)(node)
+    }
+
+    fn get(&self, node: &N) -> Option<usize> {
+        Some((self.0)(node))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry<N, C> {
+    node: N,
+    cost: C,
+    predecessor: Option<usize>,
+}
+
+/// The result of a search: the cost of every reached node, the predecessors on the cheapest paths to them and the goal
+/// that stopped the search.
+///
+/// Costs are final for all nodes if no goal was reached. Otherwise, only the costs of the nodes that were expanded
+/// before the goal, and of the goal itself, are final.
+#[derive(Debug, Clone)]
+pub struct Search<N, C, I = HashIndexer<N>> {
+    indexer: I,
+    entries: Vec<Option<Entry<N, C>>>,
+    goal: Option<usize>,
+}
+
+impl<N, C: Copy, I: Indexer<N>> Search<N, C, I> {
+    fn new(indexer: I) -> Self {
+        Self {
+            indexer,
+            entries: vec![],
+            goal: None,
+        }
+    }
+
+    fn entry(&self, node: &N) -> Option<&Entry<N, C>> {
+        self.entries.get(self.indexer.get(node)?)?.as_ref()
+    }
+
+    fn cost_at(&self, index: usize) -> Option<C> {
+        self.entries.get(index)?.as_ref().map(|entry| entry.cost)
+    }
+
+    fn insert(&mut self, index: usize, entry: Entry<N, C>) {
+        if index >= self.entries.len() {
+            self.entries.resize_with(index + 1, || None);
+        }
+        self.entries[index] = Some(entry);
+    }
+
+    /// The lowest known cost of a node, or `None` if it was not reached.
+    #[must_use]
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.entry(node).map(|entry| entry.cost)
+    }
+
+    /// The goal that stopped the search and its cost, if one was reached.
+    #[must_use]
+    pub fn goal(&self) -> Option<(&N, C)> {
+        let entry = self.entries[self.goal?].as_ref()?;
+        Some((&entry.node, entry.cost))
+    }
+
+    /// The nodes on the cheapest known path from a start to `node`, including both.
+    #[must_use]
+    pub fn path(&self, node: &N) -> Option<Vec<&N>> {
+        let mut entry = self.entry(node)?;
+        let mut path = vec![&entry.node];
+        while let Some(predecessor) = entry.predecessor {
+            entry = self.entries[predecessor].as_ref()?;
+            path.push(&entry.node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All reached nodes with their cost.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|entry| (&entry.node, entry.cost))
+    }
+}
+
+/// Breadth-first search from `starts`, where every step costs 1. Stops at the first node for which `is_goal` returns
+/// `true`, pass `|_| false` to reach every node.
+pub fn bfs<N, S>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+{
+    bfs_with(HashIndexer::default(), starts, successors, is_goal)
+}
+
+/// Like [`bfs`], with a custom [`Indexer`].
+pub fn bfs_with<N, S, I>(
+    indexer: I,
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> S,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize, I>
+where
+    N: Clone,
+    S: IntoIterator<Item = N>,
+    I: Indexer<N>,
+{
+    let mut search = Search::new(indexer);
+    let mut queue = VecDeque::new();
+
+    for node in starts {
+        let index = search.indexer.index(&node);
+        if search.cost_at(index).is_none() {
+            search.insert(index, Entry::start(node, 0));
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let Some(Entry { node, cost, .. }) = search.entries[index].clone() else {
+            continue;
+        };
+        if is_goal(&node) {
+            search.goal = Some(index);
+            break;
+        }
+
+        for next in successors(&node) {
+            let next_index = search.indexer.index(&next);
+            if search.cost_at(next_index).is_none() {
+                search.insert(next_index, Entry::step(next, cost + 1, index));
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from `starts`, with `successors` returning the next nodes and the cost of the step to them.
+/// Stops at the first node for which `is_goal` returns `true`, pass `|_| false` to reach every node.
+pub fn dijkstra<N, C, S>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    S: IntoIterator<Item = (N, C)>,
+{
+    astar_with(
+        HashIndexer::default(),
+        starts,
+        successors,
+        |_| C::zero(),
+        is_goal,
+    )
+}
+
+/// Like [`dijkstra`], with a custom [`Indexer`].
+pub fn dijkstra_with<N, C, S, I>(
+    indexer: I,
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C, I>
+where
+    N: Clone,
+    C: Copy + Ord + Zero,
+    S: IntoIterator<Item = (N, C)>,
+    I: Indexer<N>,
+{
+    astar_with(indexer, starts, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search, i.e. [`dijkstra`] guided by a `heuristic` that estimates the remaining cost to a goal.
+/// The cost of the goal is only the lowest if the heuristic never overestimates.
+pub fn astar<N, C, S>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> S,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    S: IntoIterator<Item = (N, C)>,
+{
+    astar_with(
+        HashIndexer::default(),
+        starts,
+        successors,
+        heuristic,
+        is_goal,
+    )
+}
+
+/// Like [`astar`], with a custom [`Indexer`].
+pub fn astar_with<N, C, S, I>(
+    indexer: I,
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> S,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C, I>
+where
+    N: Clone,
+    C: Copy + Ord + Zero,
+    S: IntoIterator<Item = (N, C)>,
+    I: Indexer<N>,
+{
+    let mut search = Search::new(indexer);
+    let mut heap = BinaryHeap::new();
+
+    for node in starts {
+        let index = search.indexer.index(&node);
+        if search.cost_at(index).is_none() {
+            heap.push(Reverse((heuristic(&node), C::zero(), index)));
+            search.insert(index, Entry::start(node, C::zero()));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let Some(Entry { node, .. }) = search.entries[index].clone() else {
+            continue;
+        };
+        // skip entries that were superseded by a cheaper path.
+        if search.cost_at(index).is_some_and(|best| best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(index);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let next_index = search.indexer.index(&next);
+            if search
+                .cost_at(next_index)
+                .is_some_and(|best| best <= next_cost)
+            {
+                continue;
+            }
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+            search.insert(next_index, Entry::step(next, next_cost, index));
+        }
+    }
+
+    search
+}
+
+impl<N, C> Entry<N, C> {
+    fn start(node: N, cost: C) -> Self {
+        Self {
+            node,
+            cost,
+            predecessor: None,
+        }
+    }
+
+    fn step(node: N, cost: C, predecessor: usize) -> Self {
+        Self {
+            node,
+            cost,
+            predecessor: Some(predecessor),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, bfs_with, dijkstra, dijkstra_with, DenseIndex};
+    use crate::geom::Point;
+    use crate::grid::Grid;
+
+    fn get_mock_grid() -> Grid<bool> {
+        Grid::parse(
+            [".....", ".###.", "...#.", ".#.#.", ".#..."]
+                .join("\n")
+                .as_str(),
+            |c| c == '#',
+        )
+    }
+
+    #[test]
+    fn searches_breadth_first() {
+        let grid = get_mock_grid();
+        let successors = |&pos: &(usize, usize)| grid.neighbors4(pos).filter(|&p| !grid[p]);
+
+        let search = bfs([(0, 0)], successors, |&pos| pos == (4, 4));
+        assert_eq!(search.goal(), Some((&(4, 4), 8)));
+        let path = search.path(&(4, 4)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[0], &(0, 0));
+        assert_eq!(search.cost(&(2, 2)), Some(4));
+
+        let cols = grid.cols();
+        let dense = bfs_with(
+            DenseIndex(|&(r, c): &(usize, usize)| r * cols + c),
+            [(0, 0)],
+            successors,
+            |_| false,
+        );
+        assert_eq!(dense.goal(), None);
+        assert_eq!(
+            dense.iter().count(),
+            grid.iter().filter(|&&wall| !wall).count()
+        );
+        assert_eq!(dense.cost(&(4, 4)), Some(8));
+        assert_eq!(dense.cost(&(1, 1)), None);
+    }
+
+    #[test]
+    fn searches_weighted_graphs() {
+        // a -1-> b -1-> c is cheaper than a -5-> c.
+        let edges = [('a', 'b', 1), ('b', 'c', 1), ('a', 'c', 5), ('c', 'd', 2)];
+        let successors = |&node: &char| {
+            edges
+                .iter()
+                .filter(move |(from, _, _)| *from == node)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+
+        let search = dijkstra(['a'], successors, |_| false);
+        assert_eq!(search.cost(&'c'), Some(2));
+        assert_eq!(search.cost(&'d'), Some(4));
+        assert_eq!(search.path(&'d').unwrap(), [&'a', &'b', &'c', &'d']);
+
+        let search = dijkstra_with(
+            DenseIndex(|&node: &char| (node as u8 - b'a') as usize),
+            ['a'],
+            successors,
+            |&node| node == 'c',
+        );
+        assert_eq!(search.goal(), Some((&'c', 2)));
+    }
+
+    #[test]
+    fn searches_with_heuristic() {
+        let grid = get_mock_grid();
+        let goal = Point::new(4, 4);
+        let search = astar(
+            [(0, 0)],
+            |&pos| grid.neighbors4(pos).filter(|&p| !grid[p]).map(|p| (p, 1)),
+            |&pos| Point::from(pos).manhattan(goal),
+            |&pos| pos == (4, 4),
+        );
+        assert_eq!(search.goal(), Some((&(4, 4), 8)));
+        let path = search.path(&(4, 4)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert!(path
+            .windows(2)
+            .all(|w| Point::from(*w[0]).manhattan(Point::from(*w[1])) == 1));
+    }
+}
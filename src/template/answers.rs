@@ -0,0 +1,294 @@
+/// Module that stores the accepted answers and rejected guesses of a puzzle in `data/{year}/answers/{day}.toml`.
+///
+/// ```toml
+/// [part_one]
+/// answer = "142"
+/// wrong = ["140", "150"]
+/// too_high = 150
+/// too_low = 140
+///
+/// [part_two]
+/// answer = "281"
+/// ```
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{get_data_path, submission::Outcome};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// The known answers for one part of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    /// The answer that was accepted by advent of code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// Answers that were rejected by advent of code.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// The lowest answer that was rejected as too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    /// The highest answer that was rejected as too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+}
+
+/// The reason why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadyAccepted { answer: String },
+    KnownWrong,
+    TooHigh { bound: i64 },
+    TooLow { bound: i64 },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadyAccepted { answer } => {
+                write!(f, "the part was already solved with answer {answer}")
+            }
+            Rejection::KnownWrong => write!(f, "the answer was already rejected"),
+            Rejection::TooHigh { bound } => {
+                write!(f, "the answer is too high, {bound} was already too high")
+            }
+            Rejection::TooLow { bound } => {
+                write!(f, "the answer is too low, {bound} was already too low")
+            }
+        }
+    }
+}
+
+impl PartAnswers {
+    /// Checks whether submitting `answer` could possibly succeed, based on earlier submissions.
+    pub fn check_guess(&self, answer: &str) -> Result<(), Rejection> {
+        let answer = answer.trim();
+
+        if let Some(accepted) = &self.answer {
+            return Err(Rejection::AlreadyAccepted {
+                answer: accepted.clone(),
+            });
+        }
+
+        if self.wrong.iter().any(|w| w == answer) {
+            return Err(Rejection::KnownWrong);
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            match (self.too_high, self.too_low) {
+                (Some(bound), _) if value >= bound => return Err(Rejection::TooHigh { bound }),
+                (_, Some(bound)) if value <= bound => return Err(Rejection::TooLow { bound }),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the outcome of submitting `answer`. Outcomes that say nothing about the answer are ignored.
+    pub fn record(&mut self, answer: &str, outcome: Outcome) {
+        let answer = answer.trim();
+        let value = answer.parse::<i64>().ok();
+
+        match outcome {
+            Outcome::Correct => self.answer = Some(answer.to_string()),
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect => {
+                if !self.wrong.iter().any(|w| w == answer) {
+                    self.wrong.push(answer.to_string());
+                }
+                if let Some(value) = value {
+                    if outcome == Outcome::TooHigh {
+                        self.too_high = Some(self.too_high.map_or(value, |b| b.min(value)));
+                    } else if outcome == Outcome::TooLow {
+                        self.too_low = Some(self.too_low.map_or(value, |b| b.max(value)));
+                    }
+                }
+            }
+            Outcome::Wait(_) | Outcome::AlreadyCompleted | Outcome::Unknown => {}
+        }
+    }
+}
+
+/// The known answers for both parts of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    pub part_one: PartAnswers,
+    #[serde(default)]
+    pub part_two: PartAnswers,
+}
+
+/// The result of checking an answer against the accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get_path(puzzle: PuzzleId) -> PathBuf {
+        get_data_path("answers", puzzle, &format!("{}.toml", puzzle.day))
+    }
+
+    /// Loads the answers of a puzzle. A missing answers file is treated as empty.
+    pub fn load(puzzle: PuzzleId) -> Result<Self, Error> {
+        match fs::read_to_string(Self::get_path(puzzle)) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    /// Writes the answers of a puzzle, creating the answers directory if needed.
+    pub fn save(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = Self::get_path(puzzle);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let s = toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(path, s)?;
+        Ok(())
+    }
+
+    /// Returns the answers for part `1` or `2`.
+    #[must_use]
+    pub fn part(&self, part: u8) -> &PartAnswers {
+        if part == 1 {
+            &self.part_one
+        } else {
+            &self.part_two
+        }
+    }
+
+    /// Returns the answers for part `1` or `2`.
+    pub fn part_mut(&mut self, part: u8) -> &mut PartAnswers {
+        if part == 1 {
+            &mut self.part_one
+        } else {
+            &mut self.part_two
+        }
+    }
+
+    /// Checks an answer against the accepted answer of a part.
+    #[must_use]
+    pub fn verify(&self, part: u8, answer: &str) -> Verdict {
+        match &self.part(part).answer {
+            Some(expected) if expected.trim() == answer.trim() => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, PartAnswers, Rejection, Verdict};
+    use crate::template::submission::Outcome;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("[part_one]\nanswer = \"142\"\n").unwrap();
+        assert_eq!(answers.part_one.answer.as_deref(), Some("142"));
+        assert_eq!(answers.part_two, PartAnswers::default());
+        assert!(Answers::parse("part_one = 1").is_err());
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.part_mut(2).answer = Some("281".into());
+        let s = toml::to_string(&answers).unwrap();
+        assert_eq!(Answers::parse(&s).unwrap(), answers);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let mut answers = Answers::default();
+        answers.part_mut(1).answer = Some("142".into());
+        assert_eq!(answers.verify(1, "142"), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, "143"),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(answers.verify(2, "281"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn records_submissions() {
+        let mut part = PartAnswers::default();
+        part.record("500", Outcome::TooHigh);
+        part.record("800", Outcome::TooHigh);
+        part.record("100", Outcome::TooLow);
+        part.record("abc", Outcome::Incorrect);
+        part.record("300", Outcome::Wait(None));
+
+        assert_eq!(part.wrong, vec!["500", "800", "100", "abc"]);
+        assert_eq!(part.too_high, Some(500));
+        assert_eq!(part.too_low, Some(100));
+
+        let s = toml::to_string(&part).unwrap();
+        assert_eq!(toml::from_str::<PartAnswers>(&s).unwrap(), part);
+
+        part.record("300", Outcome::Correct);
+        assert_eq!(part.answer.as_deref(), Some("300"));
+    }
+
+    #[test]
+    fn rejects_hopeless_guesses() {
+        let mut part = PartAnswers::default();
+        part.record("500", Outcome::TooHigh);
+        part.record("100", Outcome::TooLow);
+        part.record("250", Outcome::Incorrect);
+
+        assert_eq!(part.check_guess("300"), Ok(()));
+        assert_eq!(part.check_guess("250"), Err(Rejection::KnownWrong));
+        assert_eq!(
+            part.check_guess("600"),
+            Err(Rejection::TooHigh { bound: 500 })
+        );
+        assert_eq!(
+            part.check_guess("50"),
+            Err(Rejection::TooLow { bound: 100 })
+        );
+
+        part.record("300", Outcome::Correct);
+        assert_eq!(
+            part.check_guess("301"),
+            Err(Rejection::AlreadyAccepted {
+                answer: "300".into()
+            })
+        );
+    }
+}
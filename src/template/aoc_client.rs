@@ -0,0 +1,320 @@
+/// Module that talks to advent of code over HTTP, authenticated by a session cookie.
+///
+/// The session is read from `ADVENT_OF_CODE_SESSION`, `~/.adventofcode.session` or `~/.config/adventofcode.session`.
+/// The base URL defaults to `https://adventofcode.com` and can be overridden with `AOC_BASE_URL`.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use regex::Regex;
+
+use crate::template::{description::html_to_text, get_data_path, submission::Outcome};
+use crate::PuzzleId;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust v",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum Error {
+    /// No session cookie was found in the environment or the home directory.
+    MissingSession,
+    /// The session cookie was not accepted.
+    Unauthorized,
+    /// The puzzle does not exist or has not been unlocked yet.
+    NotUnlocked,
+    /// An answer was submitted too recently. Contains the remaining time to wait, if known.
+    RateLimited(Option<Duration>),
+    /// The server responded with an unexpected status code.
+    Status(u16),
+    /// The server could not be reached.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401 | 403, _) => Error::Unauthorized,
+            ureq::Error::Status(404, _) => Error::NotUnlocked,
+            ureq::Error::Status(429, _) => Error::RateLimited(None),
+            ureq::Error::Status(status, _) => Error::Status(status),
+            ureq::Error::Transport(t) => Error::Transport(t.to_string()),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session found. Set ADVENT_OF_CODE_SESSION or create ~/.adventofcode.session."
+            ),
+            Error::Unauthorized => {
+                write!(f, "the session cookie was rejected, it may have expired.")
+            }
+            Error::NotUnlocked => write!(f, "the puzzle has not been unlocked yet."),
+            Error::RateLimited(Some(wait)) => {
+                write!(f, "an answer was submitted too recently, wait {wait:?}.")
+            }
+            Error::RateLimited(None) => write!(f, "too many requests, try again later."),
+            Error::Status(status) => write!(f, "unexpected response status {status}."),
+            Error::Transport(e) => write!(f, "could not reach advent of code: {e}"),
+            Error::IO(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Creates a client from the session and base URL configured in the environment.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = read_session().ok_or(Error::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, Error> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetches the puzzle description as HTML, i.e. the `<article>` elements of the puzzle page.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, Error> {
+        let page = self.get(&self.day_url(puzzle))?;
+        let articles = extract_articles(&page);
+        if articles.is_empty() {
+            return Err(Error::NotUnlocked);
+        }
+        Ok(articles.join("\n\n"))
+    }
+
+    /// Submits an answer for part `1` or `2`.
+    pub fn submit(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<Outcome, Error> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let text = extract_articles(&response)
+            .iter()
+            .map(|a| html_to_text(a))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match Outcome::parse(&text) {
+            Outcome::Wait(wait) => Err(Error::RateLimited(wait)),
+            outcome => Ok(outcome),
+        }
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?)
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+}
+
+/// Downloads the input and the description of a puzzle to `data/{year}/inputs` and `data/{year}/puzzles`.
+pub fn download(client: &Client, puzzle: PuzzleId) -> Result<(), Error> {
+    download_to(
+        client,
+        puzzle,
+        &get_data_path("inputs", puzzle, &format!("{}.txt", puzzle.day)),
+        &get_data_path("puzzles", puzzle, &format!("{}.md", puzzle.day)),
+    )
+}
+
+fn download_to(
+    client: &Client,
+    puzzle: PuzzleId,
+    input_path: &Path,
+    puzzle_path: &Path,
+) -> Result<(), Error> {
+    let input = client.get_input(puzzle)?;
+    let description = client.get_puzzle(puzzle)?;
+
+    write_file(input_path, &input)?;
+    write_file(puzzle_path, &description)?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Prints the description of a puzzle and stores it in `data/{year}/puzzles`.
+pub fn read(client: &Client, puzzle: PuzzleId) -> Result<(), Error> {
+    let description = client.get_puzzle(puzzle)?;
+    write_file(
+        &get_data_path("puzzles", puzzle, &format!("{}.md", puzzle.day)),
+        &description,
+    )?;
+    println!("{}", html_to_text(&description));
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session);
+    }
+
+    let home = PathBuf::from(env::var_os("HOME")?);
+    [".adventofcode.session", ".config/adventofcode.session"]
+        .iter()
+        .find_map(|file| fs::read_to_string(home.join(file)).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Returns the `<article>` elements of a page.
+fn extract_articles(html: &str) -> Vec<&str> {
+    let re = Regex::new(r"(?s)<article[^>]*>.*?</article>").unwrap();
+    re.find_iter(html).map(|m| m.as_str()).collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{download_to, extract_articles, Client, Error};
+    use crate::template::{
+        mock_server::{Fixture, MockServer},
+        submission::Outcome,
+    };
+    use crate::{day, year, PuzzleId};
+
+    const SESSION: &str = "53616c7465645f5f";
+
+    #[test]
+    fn extracts_articles() {
+        let page = "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2>\n<p>a</p></article><p>Your puzzle answer was <code>1</code>.</p><article><p>b</p></article></main>";
+        assert_eq!(
+            extract_articles(page),
+            vec![
+                "<article class=\"day-desc\"><h2>--- Day 1 ---</h2>\n<p>a</p></article>",
+                "<article><p>b</p></article>"
+            ]
+        );
+        assert!(extract_articles("<main></main>").is_empty());
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let server = MockServer::start(SESSION, vec![Fixture::day_one()]);
+        let client = Client::new(&server.url(), SESSION);
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+
+        let dir = env::temp_dir().join(format!("aoc-client-test-{}", std::process::id()));
+        let input_path = dir.join("inputs").join("01.txt");
+        let puzzle_path = dir.join("puzzles").join("01.md");
+
+        download_to(&client, puzzle, &input_path, &puzzle_path).unwrap();
+
+        let input = fs::read_to_string(&input_path).unwrap();
+        let description = fs::read_to_string(&puzzle_path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(input, Fixture::day_one().input);
+        assert!(description.starts_with("<article class=\"day-desc\"><h2>--- Day 1"));
+        assert!(!description.contains("<header>"));
+        assert_eq!(
+            server.requests(),
+            vec!["GET /2023/day/1/input", "GET /2023/day/1"]
+        );
+    }
+
+    #[test]
+    fn reports_access_errors() {
+        let server = MockServer::start(SESSION, vec![Fixture::day_one()]);
+
+        let client = Client::new(&server.url(), "expired");
+        let result = client.get_input(PuzzleId::new(year!(2023), day!(1)));
+        assert!(matches!(result, Err(Error::Unauthorized)));
+
+        let client = Client::new(&server.url(), SESSION);
+        let result = client.get_puzzle(PuzzleId::new(year!(2023), day!(2)));
+        assert!(matches!(result, Err(Error::NotUnlocked)));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = MockServer::start(SESSION, vec![Fixture::day_one()]);
+        let client = Client::new(&server.url(), SESSION);
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+
+        assert_eq!(client.submit(puzzle, 1, "100").unwrap(), Outcome::TooLow);
+        assert_eq!(client.submit(puzzle, 1, "200").unwrap(), Outcome::TooHigh);
+        assert_eq!(client.submit(puzzle, 1, "abc").unwrap(), Outcome::Incorrect);
+        assert_eq!(client.submit(puzzle, 1, "142").unwrap(), Outcome::Correct);
+        assert_eq!(
+            client.submit(puzzle, 1, "142").unwrap(),
+            Outcome::AlreadyCompleted
+        );
+
+        server.set_rate_limited(true);
+        let result = client.submit(puzzle, 2, "281");
+        assert!(matches!(
+            result,
+            Err(Error::RateLimited(Some(wait))) if wait == Duration::from_secs(48)
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+/// Module that persists benchmark runs and compares them against each other.
+/// Every timed run of `all` appends one line to `data/benchmarks/history.jsonl`.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::report::Record;
+
+pub const HISTORY_PATH: &str = "data/benchmarks/history.jsonl";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The machine a benchmark was run on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Machine {
+    pub hostname: Option<String>,
+    pub os: String,
+    pub arch: String,
+    pub cpus: usize,
+}
+
+impl Machine {
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            hostname: get_hostname(),
+            os: std::env::consts::OS.into(),
+            arch: std::env::consts::ARCH.into(),
+            cpus: thread::available_parallelism().map_or(1, std::num::NonZero::get),
+        }
+    }
+}
+
+/// A single timed run of `all`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The abbreviated hash of the checked out commit.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    /// The time of the run as UTC timestamp, e.g. `2023-12-01T05:00:00Z`.
+    pub date: String,
+    pub machine: Machine,
+    pub records: Vec<Record>,
+}
+
+impl Entry {
+    /// Creates an entry for a run that finished just now.
+    #[must_use]
+    pub fn new(records: Vec<Record>) -> Self {
+        let (commit, dirty) = get_git_state();
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            commit,
+            dirty,
+            date: format_timestamp(seconds),
+            machine: Machine::current(),
+            records,
+        }
+    }
+}
+
+/// The change of a part's median execution time between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// The relative change in percent, positive values are slowdowns.
+    pub change: f64,
+    pub is_regression: bool,
+}
+
+/// Compares all parts that were solved in both runs.
+/// A part is flagged as regression if its median got slower by more than `threshold` percent.
+#[must_use]
+pub fn compare(baseline: &Entry, current: &Entry, threshold: f64) -> Vec<Comparison> {
+    current
+        .records
+        .iter()
+        .filter(|r| r.answer.is_some())
+        .filter_map(|r| {
+            let base = baseline.records.iter().find(|b| {
+                b.year == r.year && b.day == r.day && b.part == r.part && b.answer.is_some()
+            })?;
+
+            #[allow(clippy::cast_precision_loss)]
+            let change = if base.median_ns == 0 {
+                0.0
+            } else {
+                (r.median_ns as f64 - base.median_ns as f64) / base.median_ns as f64 * 100.0
+            };
+
+            Some(Comparison {
+                year: r.year,
+                day: r.day,
+                part: r.part,
+                baseline_ns: base.median_ns,
+                current_ns: r.median_ns,
+                change,
+                is_regression: change > threshold,
+            })
+        })
+        .collect()
+}
+
+fn parse_history(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Reads all entries of the history, oldest first. A missing history file is treated as empty.
+pub fn read() -> Result<Vec<Entry>, Error> {
+    match fs::read_to_string(HISTORY_PATH) {
+        Ok(s) => parse_history(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Appends an entry to the history.
+pub fn append(entry: &Entry) -> Result<(), Error> {
+    if let Some(dir) = Path::new(HISTORY_PATH).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let line = serde_json::to_string(entry).map_err(|e| Error::Parser(e.to_string()))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+fn get_git_state() -> (Option<String>, bool) {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"]);
+    let dirty =
+        git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty());
+    (commit, dirty)
+}
+
+fn get_hostname() -> Option<String> {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Formats seconds since the unix epoch as UTC timestamp.
+/// Uses the algorithm from <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+pub fn format_timestamp(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let time = seconds % 86_400;
+
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, format_timestamp, parse_history, Entry, Machine};
+    use crate::template::{report::Record, runner::Status};
+
+    fn record(day: u8, part: u8, median_ns: u64) -> Record {
+        Record {
+            year: 2023,
+            day,
+            part,
+            answer: Some("42".into()),
+            status: Status::Solved,
+            samples: 10,
+            min_ns: median_ns,
+            mean_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+            stddev_ns: 0,
+            outliers: 0,
+            error: None,
+            peak_bytes: None,
+            allocations: None,
+            allocated_bytes: None,
+        }
+    }
+
+    fn entry(commit: &str, records: Vec<Record>) -> Entry {
+        Entry {
+            commit: Some(commit.into()),
+            dirty: false,
+            date: "2023-12-25T00:00:00Z".into(),
+            machine: Machine {
+                hostname: None,
+                os: "linux".into(),
+                arch: "x86_64".into(),
+                cpus: 8,
+            },
+            records,
+        }
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_701_406_800), "2023-12-01T05:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn parses_history_lines() {
+        let first = entry("abc1234", vec![record(1, 1, 100)]);
+        let second = entry("def5678", vec![record(1, 1, 200)]);
+        let s = format!(
+            "{}\n\n{}\n",
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+        assert_eq!(parse_history(&s).unwrap(), vec![first, second]);
+        assert!(parse_history("{").is_err());
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let baseline = entry("abc1234", vec![record(17, 1, 100), record(17, 2, 100)]);
+        let current = entry(
+            "def5678",
+            vec![record(17, 1, 105), record(17, 2, 150), record(18, 1, 10)],
+        );
+
+        let comparisons = compare(&baseline, &current, 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].is_regression);
+        assert!(comparisons[1].is_regression);
+        assert_eq!(comparisons[1].change, 50.0);
+    }
+
+    #[test]
+    fn ignores_unsolved_parts() {
+        let mut unsolved = record(1, 1, 100);
+        unsolved.answer = None;
+        let baseline = entry("abc1234", vec![unsolved]);
+        let current = entry("def5678", vec![record(1, 1, 500)]);
+        assert!(compare(&baseline, &current, 10.0).is_empty());
+    }
+}
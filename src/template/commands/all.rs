@@ -1,254 +1,438 @@
-use std::io;
+use std::{
+    cmp,
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use itertools::Itertools;
 
 use crate::template::{
+    answers::Answers,
+    benchmark_history,
+    inputs::{self, Status},
+    memory,
     readme_benchmarks::{self, Timings},
+    report::{self, Format, Record},
+    runner::{self, PartResult, RunOptions, Solution},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, Day, PuzzleId, Year};
+
+/// The days that `all` runs. By default, every day of every registered year is run.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    pub year: Option<Year>,
+    /// Only these days, e.g. `10..=17` or `--days 3,8,21`.
+    pub days: Option<Vec<Day>>,
+    /// Only the given number of days that were slowest in their latest benchmark run.
+    pub slowest: Option<usize>,
+    /// Only days without an accepted answer for both parts.
+    pub only_unsolved: bool,
+}
+
+impl Selection {
+    /// Whether only some of the days of a year are run.
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        self.days.is_some() || self.slowest.is_some() || self.only_unsolved
+    }
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+    /// Returns the selected puzzles of the given years, in order.
+    fn puzzles(&self, years: &[Year]) -> Vec<PuzzleId> {
+        let mut puzzles = years
+            .iter()
+            .flat_map(|&year| {
+                self.days
+                    .clone()
+                    .unwrap_or_else(|| all_days().collect())
+                    .into_iter()
+                    .map(move |day| PuzzleId::new(year, day))
+            })
+            .filter(|&puzzle| !self.only_unsolved || !is_solved(puzzle))
+            .collect_vec();
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+        if let Some(n) = self.slowest {
+            let medians = get_latest_medians();
+            if medians.is_empty() {
+                eprintln!("No benchmark history found, run `cargo time` first to select the slowest days.");
+            }
+            puzzles.retain(|puzzle| medians.contains_key(puzzle));
+            puzzles.sort_by_key(|puzzle| cmp::Reverse(medians[puzzle]));
+            puzzles.truncate(n);
+            puzzles.sort();
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        puzzles
+    }
+}
+
+/// Whether both parts of a puzzle have an accepted answer.
+fn is_solved(puzzle: PuzzleId) -> bool {
+    Answers::load(puzzle)
+        .is_ok_and(|answers| answers.part_one.answer.is_some() && answers.part_two.answer.is_some())
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Sums the medians of the parts of every puzzle, using the latest benchmark run that included the puzzle.
+fn get_latest_medians() -> HashMap<PuzzleId, u64> {
+    let history = benchmark_history::read().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        vec![]
+    });
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    let mut medians = HashMap::new();
+    for entry in &history {
+        let mut entry_medians = HashMap::new();
+        for record in entry.records.iter().filter(|r| r.answer.is_some()) {
+            let (Some(year), Some(day)) = (Year::new(record.year), Day::new(record.day)) else {
+                continue;
+            };
+            *entry_medians.entry(PuzzleId::new(year, day)).or_default() += record.median_ns;
         }
-    });
+        medians.extend(entry_medians);
+    }
+    medians
+}
+
+/// Runs the selected days, see [`Selection`].
+/// Solutions are called in-process via the registry generated from `src/bin`.
+/// Parts that panic or time out are reported and the remaining days still run.
+///
+/// With `jobs` > 1, the days run in parallel. Timed runs and runs that count allocations are always serial, so the days
+/// do not interfere with each other's timings and allocation counts.
+pub fn handle(solutions: &[Solution], selection: &Selection, options: &RunOptions, jobs: usize) {
+    let is_timed = options.is_timed;
+    let format = options.format;
+
+    let jobs = if (is_timed || options.mem) && jobs > 1 {
+        let flag = if is_timed { "--time" } else { "--mem" };
+        eprintln!("Running serially, --jobs is ignored with {flag}.");
+        1
+    } else {
+        jobs
+    };
+
+    let results = run(solutions, selection, options, jobs);
+
+    let timings: Vec<Timings> = results
+        .iter()
+        .map(|r| r.puzzle)
+        .dedup()
+        .map(|puzzle| {
+            let day_results = results
+                .iter()
+                .filter(|r| r.puzzle == puzzle)
+                .cloned()
+                .collect_vec();
+            get_timings(puzzle, &day_results, is_timed)
+        })
+        .collect();
+
+    let is_text = format == Format::Text;
+
+    if let Some(report) = report::render(&results, format) {
+        println!("{report}");
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+        // only persist benchmarks of optimized builds.
+        if !cfg!(debug_assertions) {
+            match readme_benchmarks::update(timings, selection.is_partial()) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            let entry = benchmark_history::Entry::new(results.iter().map(Record::from).collect());
+            match benchmark_history::append(&entry) {
+                Ok(()) => println!(
+                    "Successfully recorded benchmarks in \"{}\".",
+                    benchmark_history::HISTORY_PATH
+                ),
+                Err(e) => eprintln!("Failed to record benchmarks: {e}"),
+            }
         }
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
+/// Runs the solutions of the selected days, of every registered year if no year is selected.
+/// Days without a solution or with a missing or empty input are skipped. Progress is printed if `options.format` is [`Format::Text`].
+///
+/// With more than one job, the days of a year run in parallel, see [`run_parallel`].
+pub fn run(
+    solutions: &[Solution],
+    selection: &Selection,
+    options: &RunOptions,
+    jobs: usize,
+) -> Vec<PartResult> {
+    let mut results: Vec<PartResult> = vec![];
+    let print = |line: &str| print_line(line, options);
+
+    let years = selection
+        .year
+        .map_or_else(|| get_registered_years(solutions), |year| vec![year]);
+    let selected = selection.puzzles(&years);
+
+    for (i, &year) in years.iter().enumerate() {
+        if years.len() > 1 {
+            if i > 0 {
+                print("");
+            }
+            print(&format!("{ANSI_BOLD}Year {year}{ANSI_RESET}"));
+            print("=========");
+            print("");
+        }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+        let puzzles = selected
+            .iter()
+            .copied()
+            .filter(|puzzle| puzzle.year == year)
+            .collect_vec();
+
+        if puzzles.is_empty() {
+            print("No days selected.");
+        } else if jobs > 1 {
+            results.extend(run_parallel(solutions, &puzzles, options, jobs));
+        } else {
+            for (i, &puzzle) in puzzles.iter().enumerate() {
+                results.extend(run_day(solutions, puzzle, options, i == 0));
+            }
+        }
     }
-}
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    results
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
+/// Runs the days on `jobs` threads. The output of every day is captured and printed once the day and all days before
+/// it have finished, so it appears in the same order as in a serial run.
+fn run_parallel(
+    solutions: &[Solution],
+    puzzles: &[PuzzleId],
+    options: &RunOptions,
+    jobs: usize,
+) -> Vec<PartResult> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&puzzle) = puzzles.get(i) else {
+                    break;
+                };
+                let day = runner::capture_output(|| run_day(solutions, puzzle, options, i == 0));
+                if sender.send((i, day)).is_err() {
+                    break;
+                }
+            });
         }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut results = vec![];
+        let mut printed = 0;
+
+        for (i, day) in receiver {
+            finished.insert(i, day);
+            while let Some((day_results, output)) = finished.remove(&printed) {
+                runner::write_output(&output);
+                results.extend(day_results);
+                printed += 1;
+            }
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        results
+    })
+}
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+/// Runs the solution of a single day. Days without a solution or with a missing or empty input are skipped.
+fn run_day(
+    solutions: &[Solution],
+    puzzle: PuzzleId,
+    options: &RunOptions,
+    is_first: bool,
+) -> Vec<PartResult> {
+    let print = |line: &str| print_line(line, options);
+    let day = puzzle.day;
+
+    if !is_first {
+        print("");
+    }
 
-        let mut output = vec![];
+    print(&format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"));
+    print("------");
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    let Some(solution) = solutions.iter().find(|s| s.puzzle == puzzle) else {
+        print("Not solved.");
+        return vec![];
+    };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    let input = match inputs::read(puzzle) {
+        (_, Status::Missing) => {
+            print(&format!(
+                "Missing input file \"{}\".",
+                inputs::get_path(puzzle).display()
+            ));
+            return vec![];
         }
+        (_, Status::Empty) => {
+            print(&format!(
+                "Empty input file \"{}\".",
+                inputs::get_path(puzzle).display()
+            ));
+            return vec![];
+        }
+        (input, Status::Modified(modification)) => {
+            inputs::warn(puzzle, modification);
+            input
+        }
+        (input, Status::Unrecorded | Status::Intact) => input,
+    };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    (solution.run)(&input, options)
+}
 
-        Ok(output)
+/// Prints a line of progress. In machine-readable formats, only the final report is printed to stdout.
+fn print_line(line: &str, options: &RunOptions) {
+    if options.format == Format::Text {
+        runner::write_output(&format!("{line}\n"));
     }
+}
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+/// Collects the years that have at least one registered solution.
+fn get_registered_years(solutions: &[Solution]) -> Vec<Year> {
+    let mut years: Vec<Year> = solutions.iter().map(|s| s.puzzle.year).collect();
+    years.dedup();
+    years
+}
 
-        output
+/// Converts the results of a solution run into benchmark timings. Parts without an answer have no timing.
+fn get_timings(puzzle: PuzzleId, results: &[PartResult], is_timed: bool) -> Timings {
+    let mut timings = Timings {
+        puzzle,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        peak_memory: results
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            .filter_map(|r| r.memory.map(|m| m.peak_bytes))
+            .max()
+            .map(memory::format_bytes),
+    };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    if !is_timed {
+        return timings;
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    for result in results.iter().filter(|r| r.answer.is_some()) {
+        let timing = Some(format!("{:.1?}", result.stats.median));
+        match result.part {
+            1 => timings.part_1 = timing,
+            2 => timings.part_2 = timing,
+            _ => continue,
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.stats.median.as_nanos() as f64;
+        timings.total_nanos += nanos;
+    }
 
-                timings.total_nanos += nanos;
-            });
+    timings
+}
 
-        timings
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::get_timings;
+    use crate::template::memory::MemStats;
+    use crate::template::runner::{PartResult, Stats, Status};
+    use crate::{day, year, PuzzleId};
+
+    fn part_result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            part,
+            answer: answer.map(Into::into),
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            stats: Stats::from_samples(&[Duration::from_nanos(nanos)]),
+            error: None,
+            memory: None,
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[test]
+    fn test_well_formed() {
+        let res = get_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[
+                part_result(1, Some("0"), 74_130),
+                part_result(2, Some("10"), 74_130_000),
+            ],
+            true,
+        );
+        assert_eq!(res.total_nanos, 74_204_130_f64);
+        assert_eq!(res.part_1.unwrap(), "74.1µs");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+        assert_eq!(res.peak_memory, None);
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_peak_memory() {
+        let mut results = [
+            part_result(1, Some("0"), 100),
+            part_result(2, Some("10"), 100),
+        ];
+        results[0].memory = Some(MemStats {
+            peak_bytes: 3 << 20,
+            ..MemStats::default()
+        });
+        results[1].memory = Some(MemStats {
+            peak_bytes: 1024,
+            ..MemStats::default()
+        });
+        let res = get_timings(PuzzleId::new(year!(2023), day!(1)), &results, true);
+        assert_eq!(res.peak_memory.as_deref(), Some("3.0 MiB"));
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_missing_parts() {
+        let res = get_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[part_result(1, None, 100), part_result(2, None, 100)],
+            true,
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
-
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_untimed() {
+        let res = get_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[part_result(1, Some("0"), 100)],
+            false,
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
     }
 }
@@ -0,0 +1,96 @@
+use std::{process, time::Duration};
+
+use crate::template::{
+    benchmark_history::{self, Entry},
+    ANSI_BOLD, ANSI_RESET,
+};
+
+/// Compares two runs from the benchmark history and exits with a non-zero status if any part regressed.
+///
+/// `current` defaults to the latest run and `baseline` to the run before it. Both can be selected by (a prefix of) their commit hash.
+pub fn handle(baseline: Option<String>, current: Option<String>, threshold: f64) {
+    let history = match benchmark_history::read() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(current_index) = find_entry(&history, history.len(), current.as_deref()) else {
+        eprintln!(
+            "No matching run found in \"{}\". Run `cargo time` to record one.",
+            benchmark_history::HISTORY_PATH
+        );
+        process::exit(1);
+    };
+
+    let Some(baseline_index) = find_entry(&history, current_index, baseline.as_deref()) else {
+        eprintln!("No baseline run found before the current run.");
+        process::exit(1);
+    };
+
+    let baseline = &history[baseline_index];
+    let current = &history[current_index];
+
+    println!(
+        "Comparing {} against baseline {} (threshold: {threshold}%)",
+        describe(current),
+        describe(baseline)
+    );
+
+    if baseline.machine != current.machine {
+        println!("Warning: the runs were recorded on different machines.");
+    }
+
+    println!();
+
+    let comparisons = benchmark_history::compare(baseline, current, threshold);
+
+    for c in &comparisons {
+        let line = format!(
+            "{} Day {:02}, Part {}: {:.1?} → {:.1?} ({:+.1}%)",
+            c.year,
+            c.day,
+            c.part,
+            Duration::from_nanos(c.baseline_ns),
+            Duration::from_nanos(c.current_ns),
+            c.change
+        );
+        if c.is_regression {
+            println!("{ANSI_BOLD}{line} ▲ regression{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.is_regression).count();
+
+    println!();
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} part(s) got slower by more than {threshold}%.{ANSI_RESET}"
+        );
+        process::exit(1);
+    } else {
+        println!("No regressions found.");
+    }
+}
+
+/// Finds the latest entry before `end` whose commit starts with `commit`, or the entry right before `end` if no commit is given.
+fn find_entry(history: &[Entry], end: usize, commit: Option<&str>) -> Option<usize> {
+    match commit {
+        Some(commit) => history[..end].iter().rposition(|e| {
+            e.commit
+                .as_deref()
+                .is_some_and(|c| c.starts_with(commit) || commit.starts_with(c))
+        }),
+        None => end.checked_sub(1),
+    }
+}
+
+fn describe(entry: &Entry) -> String {
+    let commit = entry.commit.as_deref().unwrap_or("unknown commit");
+    let dirty = if entry.dirty { "-dirty" } else { "" };
+    format!("{commit}{dirty} ({})", entry.date)
+}
@@ -1,15 +1,38 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::{aoc_cli, aoc_client, inputs};
+use crate::PuzzleId;
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Downloads input and description of a puzzle, via HTTP if a session is configured and via aoc-cli otherwise.
+/// The checksum of the downloaded input is recorded to detect later edits.
+pub fn handle(puzzle: PuzzleId) {
+    match aoc_client::Client::from_env() {
+        Ok(client) => {
+            if let Err(e) = aoc_client::download(&client, puzzle) {
+                eprintln!("failed to download puzzle: {e}");
+                process::exit(1);
+            }
+        }
+        Err(aoc_client::Error::MissingSession) => {
+            if aoc_cli::check().is_err() {
+                eprintln!("no session found and command \"aoc\" not found or not callable. Set ADVENT_OF_CODE_SESSION or try running \"cargo install aoc-cli\" to install it.");
+                process::exit(1);
+            }
+
+            if let Err(e) = aoc_cli::download(puzzle) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            };
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    let recorded = fs::read_to_string(inputs::get_path(puzzle))
+        .map_err(inputs::Error::from)
+        .and_then(|input| inputs::record(puzzle, &input));
+    if let Err(e) = recorded {
+        eprintln!("Failed to record the checksum of the input: {e}");
+    }
 }
@@ -0,0 +1,239 @@
+use std::{fs, path::Path, process};
+
+use regex::Regex;
+
+use crate::template::{commands::scaffold::format_expected, description, get_data_path};
+use crate::PuzzleId;
+
+/// Extracts the examples of a puzzle from its downloaded description.
+///
+/// Writes the example of part one to `NN.txt` and, if part two has a different example, to `NN-2.txt`.
+/// Existing examples are kept. The expected answers in the tests of `src/bin/{puzzle}.rs` are updated.
+pub fn handle(puzzle: PuzzleId) {
+    let Some(description) = description::read(puzzle) else {
+        eprintln!(
+            "No puzzle description found. Run `cargo download {}` first.",
+            puzzle.day.into_inner()
+        );
+        process::exit(1);
+    };
+
+    let example_one = description::example_input(&description, 1);
+    let example_two = description::example_input(&description, 2)
+        .filter(|e| example_one.as_ref().is_some_and(|one| one != e));
+
+    match &example_one {
+        Some(example) => write_example(
+            &get_data_path("examples", puzzle, &format!("{}.txt", puzzle.day)),
+            example,
+        ),
+        None => println!("No example found in the puzzle description."),
+    }
+
+    if let Some(example) = &example_two {
+        write_example(
+            &get_data_path("examples", puzzle, &format!("{}-2.txt", puzzle.day)),
+            example,
+        );
+    }
+
+    let module_path = format!("src/bin/{puzzle}.rs");
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        println!("No module file \"{module_path}\" found, skipping tests.");
+        return;
+    };
+
+    for part in [1, 2] {
+        if let Some(answer) = description::example_answer(&description, part) {
+            if let Some(patched) = patch_expected(&module, part, &answer) {
+                println!("Set expected example answer of part {part} to {answer}.");
+                module = patched;
+            }
+        }
+    }
+
+    if example_two.is_some() {
+        if let Some(patched) = patch_part_two_input(&module) {
+            println!("Set example of part 2 to \"{}-2.txt\".", puzzle.day);
+            module = patched;
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to update module file: {e}");
+        process::exit(1);
+    }
+}
+
+fn write_example(path: &Path, example: &str) {
+    if fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty()) {
+        println!(
+            "Example file \"{}\" already exists, skipping.",
+            path.display()
+        );
+        return;
+    }
+
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, example));
+
+    match written {
+        Ok(()) => println!("Created example file \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to create example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Replaces the expected answer of the test of a part. Returns `None` if the module is unchanged.
+fn patch_expected(module: &str, part: u8, answer: &str) -> Option<String> {
+    let name = if part == 1 { "one" } else { "two" };
+
+    let answer_type = answer_type(module, name).unwrap_or("u32");
+
+    let test = Regex::new(&format!(
+        r"(?s)(fn test_part_{name}\(\)\s*\{{.*?assert_eq!\(result,\s*)(.*?)(\);)"
+    ))
+    .unwrap();
+
+    let expected = format_expected(Some(answer), answer_type);
+    let current = test.captures(module)?.get(2)?;
+    if current.as_str() == expected {
+        return None;
+    }
+
+    Some(format!(
+        "{}{expected}{}",
+        &module[..current.start()],
+        &module[current.end()..]
+    ))
+}
+
+/// Reads the answer type `T` from the `-> Option<T>` signature of a part.
+fn answer_type<'a>(module: &'a str, name: &str) -> Option<&'a str> {
+    // the arguments differ for solutions with parameters and may be wrapped over several lines.
+    let signature = Regex::new(&format!(
+        r"fn part_{name}\([^)]*\)\s*->\s*Option<(.+)>\s*\{{"
+    ))
+    .unwrap();
+    Some(signature.captures(module)?.get(1)?.as_str())
+}
+
+/// Makes the test of part two read `NN-2.txt`. Returns `None` if the module is unchanged.
+fn patch_part_two_input(module: &str) -> Option<String> {
+    let test =
+        Regex::new(r#"(?s)fn test_part_two\(\)\s*\{.*?(read_file\("examples", PUZZLE\))"#).unwrap();
+    let input = test.captures(module)?.get(1)?;
+
+    Some(format!(
+        "{}read_file_part(\"examples\", PUZZLE, 2){}",
+        &module[..input.start()],
+        &module[input.end()..]
+    ))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type, patch_expected, patch_part_two_input};
+
+    const MODULE: &str = r#"advent_of_code::solution!(2023, 1);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn patches_expected_answers() {
+        let module = patch_expected(MODULE, 1, "142").unwrap();
+        assert!(module.contains("assert_eq!(result, Some(142));"));
+        assert_eq!(patch_expected(&module, 1, "142"), None);
+
+        let module = patch_expected(&module, 2, "281").unwrap();
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"281\")));"));
+        assert!(!module.contains("assert_eq!(result, None);"));
+    }
+
+    #[test]
+    fn patches_expected_answers_with_params() {
+        let module = r#"advent_of_code::solution!(2023, 21, params);
+
+pub fn part_one(input: &str, params: &Params) -> Option<String> {
+    None
+}
+
+pub fn part_two(
+    input: &str,
+    params: &Params,
+) -> Option<Vec<u64>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            &Params::default(),
+        );
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+        assert_eq!(answer_type(module, "one"), Some("String"));
+        assert_eq!(answer_type(module, "two"), Some("Vec<u64>"));
+
+        let module = patch_expected(module, 1, "142").unwrap();
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"142\")));"));
+
+        let module = patch_expected(&module, 2, "281").unwrap();
+        assert!(module.contains("assert_eq!(result, Some(281));"));
+    }
+
+    #[test]
+    fn patches_part_two_input() {
+        let module = patch_part_two_input(MODULE).unwrap();
+        assert_eq!(module.matches("read_file(\"examples\", PUZZLE)").count(), 1);
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2))"
+        ));
+        assert_eq!(patch_part_two_input(&module), None);
+    }
+}
@@ -1,16 +1,31 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::{aoc_cli, aoc_client};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+/// Prints the description of a puzzle, via HTTP if a session is configured and via aoc-cli otherwise.
+pub fn handle(puzzle: PuzzleId) {
+    match aoc_client::Client::from_env() {
+        Ok(client) => {
+            if let Err(e) = aoc_client::read(&client, puzzle) {
+                eprintln!("failed to read puzzle: {e}");
+                process::exit(1);
+            }
+        }
+        Err(aoc_client::Error::MissingSession) => {
+            if aoc_cli::check().is_err() {
+                eprintln!("no session found and command \"aoc\" not found or not callable. Set ADVENT_OF_CODE_SESSION or try running \"cargo install aoc-cli\" to install it.");
+                process::exit(1);
+            }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+            if let Err(e) = aoc_cli::read(puzzle) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            };
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
 }
@@ -1,18 +1,24 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::{description, get_data_path};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The path of a user-provided module template, which replaces [`MODULE_TEMPLATE`] if present.
+pub const TEMPLATE_PATH: &str = "templates/solution.rs";
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// The default module template. Placeholders are replaced by [`render`].
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!({{YEAR}}, {{DAY}});
+
+pub fn part_one(input: &str) -> Option<{{TYPE}}> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
     None
 }
 
@@ -22,30 +28,106 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&{{PART_ONE_INPUT}});
+        assert_eq!(result, {{PART_ONE_EXPECTED}});
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&{{PART_TWO_INPUT}});
+        assert_eq!(result, {{PART_TWO_EXPECTED}});
     }
 }
 "#;
 
+/// The options of a scaffolded module.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// The answer type of both parts, e.g. `u64`.
+    pub answer_type: String,
+    /// The expected example answers of part one and two.
+    pub expected: [Option<String>; 2],
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            answer_type: "u32".into(),
+            expected: [None, None],
+        }
+    }
+}
+
+/// Replaces the placeholders of a module template:
+///
+/// - `{{YEAR}}`, `{{DAY}}`: the puzzle, e.g. `2023` and `1`.
+/// - `{{TYPE}}`: the answer type, e.g. `u32`.
+/// - `{{PART_ONE_INPUT}}`, `{{PART_TWO_INPUT}}`: the expression that reads the example of a part.
+/// - `{{PART_ONE_EXPECTED}}`, `{{PART_TWO_EXPECTED}}`: the expected example answer, e.g. `Some(142)` or `None`.
+#[must_use]
+pub fn render(template: &str, puzzle: PuzzleId, options: &Options, inputs: [&str; 2]) -> String {
+    let [expected_one, expected_two] = options
+        .expected
+        .clone()
+        .map(|e| format_expected(e.as_deref(), &options.answer_type));
+
+    template
+        .replace("{{YEAR}}", &puzzle.year.into_inner().to_string())
+        .replace("{{DAY}}", &puzzle.day.into_inner().to_string())
+        .replace("{{TYPE}}", &options.answer_type)
+        .replace("{{PART_ONE_INPUT}}", inputs[0])
+        .replace("{{PART_TWO_INPUT}}", inputs[1])
+        .replace("{{PART_ONE_EXPECTED}}", &expected_one)
+        .replace("{{PART_TWO_EXPECTED}}", &expected_two)
+}
+
+/// Formats an expected answer as Rust expression, e.g. `Some(142)` or `None`.
+#[must_use]
+pub fn format_expected(expected: Option<&str>, answer_type: &str) -> String {
+    match expected {
+        None => "None".into(),
+        Some(e) if answer_type != "String" && e.parse::<i128>().is_ok() => format!("Some({e})"),
+        Some(e) => format!("Some(String::from({e:?}))"),
+    }
+}
+
+/// Returns the expression that reads the example of a part. Part two uses `NN-2.txt` if it exists.
+fn get_example_input(puzzle: PuzzleId, part: u8) -> String {
+    let part_path = get_data_path("examples", puzzle, &format!("{}-{part}.txt", puzzle.day));
+
+    if part == 2 && part_path.exists() {
+        "advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2)".into()
+    } else {
+        "advent_of_code::template::read_file(\"examples\", PUZZLE)".into()
+    }
+}
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Creates the module of a puzzle from `templates/solution.rs`, or the default template if it does not exist.
+/// Expected example answers that are not given in `options` are taken from the puzzle description, if it was downloaded.
+pub fn handle(puzzle: PuzzleId, mut options: Options) {
+    let file_name = format!("{}.txt", puzzle.day);
+    let input_path = get_data_path("inputs", puzzle, &file_name)
+        .to_string_lossy()
+        .into_owned();
+    let example_path = get_data_path("examples", puzzle, &file_name)
+        .to_string_lossy()
+        .into_owned();
+    let module_path = format!("src/bin/{puzzle}.rs");
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -55,11 +137,26 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let template = match fs::read_to_string(TEMPLATE_PATH) {
+        Ok(template) => {
+            println!("Using module template \"{TEMPLATE_PATH}\"");
+            template
+        }
+        Err(_) => MODULE_TEMPLATE.into(),
+    };
+
+    if let Some(description) = description::read(puzzle) {
+        for (i, expected) in options.expected.iter_mut().enumerate() {
+            if expected.is_none() {
+                *expected = description::example_answer(&description, i as u8 + 1);
+            }
+        }
+    }
+
+    let inputs = [get_example_input(puzzle, 1), get_example_input(puzzle, 2)];
+    let module = render(&template, puzzle, &options, [&inputs[0], &inputs[1]]);
+
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -90,5 +187,53 @@ pub fn handle(day: Day) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, Options, MODULE_TEMPLATE};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn renders_default_template() {
+        let options = Options {
+            answer_type: "u64".into(),
+            expected: [Some("142".into()), None],
+        };
+        let module = render(
+            MODULE_TEMPLATE,
+            PuzzleId::new(year!(2023), day!(1)),
+            &options,
+            ["example(1)", "example(2)"],
+        );
+
+        assert!(module.starts_with("advent_of_code::solution!(2023, 1);"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<u64> {"));
+        assert!(module.contains("let result = part_two(&example(2));"));
+        assert!(module.contains("assert_eq!(result, Some(142));"));
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn renders_string_answers() {
+        let options = Options {
+            answer_type: "String".into(),
+            expected: [Some("142".into()), Some("EZFCHJAB".into())],
+        };
+        let module = render(
+            "{{PART_ONE_EXPECTED}} {{PART_TWO_EXPECTED}}",
+            PuzzleId::new(year!(2023), day!(1)),
+            &options,
+            ["", ""],
+        );
+        assert_eq!(
+            module,
+            "Some(String::from(\"142\")) Some(String::from(\"EZFCHJAB\"))"
+        );
+    }
 }
@@ -1,31 +1,282 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    path::PathBuf,
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
 
-use crate::Day;
+use crate::template::{
+    get_data_path,
+    inputs::Source,
+    report::{Format, Record},
+    runner::RunOptions,
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Runs the solution of a puzzle via `cargo run`. With `watch`, the solution is re-run whenever its files change.
+pub fn handle(puzzle: PuzzleId, release: bool, watch: bool, options: &RunOptions) {
+    if watch {
+        if options.submit.is_some() {
+            eprintln!("--submit can not be combined with --watch.");
+            process::exit(1);
+        }
+        if options.input == Source::Stdin {
+            eprintln!("--input - can not be combined with --watch.");
+            process::exit(1);
+        }
+        watch_puzzle(puzzle, release, options);
+        return;
+    }
+
+    let cmd_args = build_args(puzzle, release, options);
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap();
+}
+
+fn build_args(puzzle: PuzzleId, release: bool, options: &RunOptions) -> Vec<String> {
+    let format = options.format;
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    // keep cargo's build output from mixing with machine-readable output.
+    if format != Format::Text {
+        cmd_args.push("--quiet".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(timeout) = options.timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_millis().to_string());
+    }
+
+    if options.mem {
+        cmd_args.push("--mem".to_string());
+    }
+
+    cmd_args.extend(options.input.to_args());
+    cmd_args.extend(options.params.to_args());
+
+    if options.is_timed {
+        let bench = options.bench;
         cmd_args.push("--time".to_string());
+        cmd_args.push("--warm-up".to_string());
+        cmd_args.push(bench.warm_up.as_millis().to_string());
+        cmd_args.push("--bench-time".to_string());
+        cmd_args.push(bench.time_budget.as_millis().to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
+    if format != Format::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    cmd_args
+}
+
+/// Re-runs the example tests and the solution whenever the module, the examples or the input of a puzzle change.
+fn watch_puzzle(puzzle: PuzzleId, release: bool, options: &RunOptions) {
+    let mut previous: Vec<Record> = vec![];
+    let mut fingerprint = get_fingerprint(puzzle, &options.input);
+
+    loop {
+        println!("{ANSI_BOLD}Running {puzzle}...{ANSI_RESET}");
+
+        if let Some(records) = run_once(puzzle, release, options) {
+            for line in format_changes(&previous, &records) {
+                println!("{line}");
+            }
+            previous = records;
+        }
+
+        println!("{ANSI_ITALIC}Watching for changes, press Ctrl+C to stop.{ANSI_RESET}");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = get_fingerprint(puzzle, &options.input);
+            if current != fingerprint {
+                fingerprint = current;
+                break;
+            }
+        }
+
+        println!();
+    }
+}
+
+/// Builds the solution, runs its tests and then runs it on the selected input.
+/// Returns `None` if the solution could not be built or run.
+fn run_once(puzzle: PuzzleId, release: bool, options: &RunOptions) -> Option<Vec<Record>> {
+    let profile: &[&str] = if release { &["--release"] } else { &[] };
+    let bin = puzzle.to_string();
+
+    let built = Command::new("cargo")
+        .args(["build", "--quiet", "--bin", &bin])
+        .args(profile)
+        .status()
+        .is_ok_and(|s| s.success());
+
+    if !built {
+        return None;
+    }
+
+    let tested = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &bin])
+        .args(profile)
+        .stdout(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success());
+
+    if tested {
+        println!("Example tests: ✔");
+    } else {
+        println!("Example tests: ✘ (run `cargo test --bin {bin}` for details)");
+    }
+
+    // the input is not downloaded interactively while watching.
+    let options = RunOptions {
+        format: Format::Json,
+        ..options.clone()
+    };
+    let output = Command::new("cargo")
+        .args(build_args(puzzle, release, &options))
+        .stdin(Stdio::null())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .output()
+        .ok()?;
 
-    cmd.wait().unwrap();
+    serde_json::from_slice(&output.stdout).ok()
+}
+
+/// Describes the answers of a run compared to the previous run, one line per part.
+fn format_changes(previous: &[Record], current: &[Record]) -> Vec<String> {
+    current
+        .iter()
+        .map(|record| {
+            let answer = record.answer.as_deref().unwrap_or("✖");
+            let before = previous
+                .iter()
+                .find(|p| p.part == record.part)
+                .map(|p| p.answer.as_deref().unwrap_or("✖"));
+
+            let change = match before {
+                Some(before) if before != answer => format!(" (was {before})"),
+                Some(_) => " (unchanged)".to_string(),
+                None => String::new(),
+            };
+
+            let timing = if record.samples > 0 && record.answer.is_some() {
+                format!(" {:.1?}", Duration::from_nanos(record.median_ns))
+            } else {
+                String::new()
+            };
+
+            format!(
+                "Part {}: {ANSI_BOLD}{answer}{ANSI_RESET}{change}{timing}",
+                record.part
+            )
+        })
+        .collect()
+}
+
+/// Collects the modification times of the watched files of a puzzle.
+fn get_fingerprint(puzzle: PuzzleId, input: &Source) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let day = puzzle.day.to_string();
+    let examples_dir = get_data_path("examples", puzzle, "");
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{puzzle}.rs")),
+        get_data_path("inputs", puzzle, &format!("{day}.txt")),
+    ];
+
+    // `NN.txt`, `NN-2.txt` and the named cases in `NN/`.
+    let list = |dir: PathBuf| {
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .collect::<Vec<_>>()
+    };
+    paths.extend(list(examples_dir.clone()).into_iter().filter(|p| {
+        p.file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with(&day) && n.ends_with(".txt"))
+    }));
+    paths.extend(list(examples_dir.join(&day)));
+    if let Source::File(path) = input {
+        paths.push(path.clone());
+    }
+
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_changes;
+    use crate::template::{report::Record, runner::Status};
+
+    fn record(part: u8, answer: Option<&str>) -> Record {
+        Record {
+            year: 2023,
+            day: 1,
+            part,
+            answer: answer.map(Into::into),
+            status: Status::Solved,
+            samples: 0,
+            min_ns: 0,
+            mean_ns: 0,
+            median_ns: 0,
+            p95_ns: 0,
+            stddev_ns: 0,
+            outliers: 0,
+            error: None,
+            peak_bytes: None,
+            allocations: None,
+            allocated_bytes: None,
+        }
+    }
+
+    #[test]
+    fn formats_answer_changes() {
+        let first = vec![record(1, Some("142")), record(2, None)];
+        let lines = format_changes(&[], &first);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("Part 1: \x1b[1m142\x1b[0m"));
+        assert!(!lines[0].contains("was"));
+
+        let second = vec![record(1, Some("142")), record(2, Some("281"))];
+        let lines = format_changes(&first, &second);
+        assert!(lines[0].ends_with("(unchanged)"));
+        assert!(lines[1].ends_with("(was ✖)"));
+    }
 }
@@ -0,0 +1,68 @@
+use std::{process, time::Duration};
+
+use crate::template::{
+    commands::all,
+    runner::{RunOptions, Solution, Status},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::Year;
+
+/// Runs all solutions and checks their answers against `data/{year}/answers`.
+/// Exits with a non-zero status if any answer does not match the accepted answer, if a part panicked or timed out, or if
+/// no answer could be checked at all, i.e. no day has both an input and an accepted answer.
+/// A part that runs longer than `timeout` counts as failed. With `jobs` > 1, the days run in parallel.
+pub fn handle(solutions: &[Solution], year: Option<Year>, timeout: Option<Duration>, jobs: usize) {
+    let options = RunOptions {
+        verify: true,
+        timeout,
+        ..RunOptions::default()
+    };
+
+    let results = all::run(
+        solutions,
+        &all::Selection {
+            year,
+            ..all::Selection::default()
+        },
+        &options,
+        jobs,
+    );
+    let count = |status: Status| results.iter().filter(|r| r.status == status).count();
+
+    let incorrect = count(Status::Incorrect);
+    let failed = count(Status::Panicked) + count(Status::TimedOut);
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {} correct, {incorrect} incorrect, {} without accepted answer, {} unsolved, {failed} failed.",
+        count(Status::Correct),
+        count(Status::Solved),
+        count(Status::Unsolved)
+    );
+
+    for result in results.iter().filter(|r| r.status == Status::Incorrect) {
+        println!(
+            "✘ {} Day {}, Part {}",
+            result.puzzle.year, result.puzzle.day, result.part
+        );
+    }
+
+    for result in results.iter().filter(|r| r.error.is_some()) {
+        println!(
+            "✖ {} Day {}, Part {}: {}",
+            result.puzzle.year,
+            result.puzzle.day,
+            result.part,
+            result.error.as_deref().unwrap_or_default()
+        );
+    }
+
+    if incorrect + failed > 0 {
+        process::exit(1);
+    }
+
+    if count(Status::Correct) == 0 {
+        eprintln!("Nothing to verify: no day has both an input and an accepted answer in data/{{year}}/answers.");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,136 @@
+/// Module that extracts information from the puzzle descriptions in `data/{year}/puzzles`.
+/// Descriptions are either HTML articles, as saved by `download`, or markdown, as saved by aoc-cli.
+use std::fs;
+
+use regex::Regex;
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+/// Reads the description of a puzzle, if it was downloaded.
+#[must_use]
+pub fn read(puzzle: PuzzleId) -> Option<String> {
+    fs::read_to_string(get_data_path(
+        "puzzles",
+        puzzle,
+        &format!("{}.md", puzzle.day),
+    ))
+    .ok()
+}
+
+/// Returns the description of part `1` or `2`. Part two is only part of the description once part one is solved.
+#[must_use]
+pub fn part(description: &str, part: u8) -> Option<&str> {
+    let (one, two) = match description.find("--- Part Two ---") {
+        Some(i) => {
+            // part two starts with its own article in HTML, or with the line of the heading in markdown.
+            let start = description[..i]
+                .rfind("<article")
+                .or_else(|| description[..i].rfind('\n'))
+                .unwrap_or(i);
+            (&description[..start], Some(&description[start..]))
+        }
+        None => (description, None),
+    };
+
+    if part == 1 {
+        Some(one)
+    } else {
+        two
+    }
+}
+
+/// Returns the example answer of a part, which is the last emphasized code in its description.
+#[must_use]
+pub fn example_answer(description: &str, part_number: u8) -> Option<String> {
+    let re = Regex::new(
+        r"<code><em>([^<]+)</em></code>|<em><code>([^<]+)</code></em>|\*{1,2}`([^`]+)`\*{1,2}",
+    )
+    .unwrap();
+
+    re.captures_iter(part(description, part_number)?)
+        .filter_map(|c| c.iter().skip(1).flatten().next())
+        .map(|m| m.as_str().trim().to_string())
+        .last()
+}
+
+/// Returns the first example input in the description of a part, i.e. its first `<pre><code>` or fenced code block.
+#[must_use]
+pub fn example_input(description: &str, part_number: u8) -> Option<String> {
+    let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>|```[^\n]*\n(.*?)```").unwrap();
+
+    let captures = re.captures(part(description, part_number)?)?;
+    let example = match (captures.get(1), captures.get(2)) {
+        (Some(html), _) => html_to_text(html.as_str()),
+        (_, Some(markdown)) => markdown.as_str().to_string(),
+        _ => return None,
+    };
+
+    Some(example)
+}
+
+/// Strips the tags of an HTML fragment and decodes the common entities.
+#[must_use]
+pub fn html_to_text(html: &str) -> String {
+    let re = Regex::new(r"<[^>]*>").unwrap();
+    re.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{example_answer, example_input, html_to_text, part};
+
+    const HTML: &str = "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p><pre><code>1abc2\n<em>pqr3</em>stu8vwx\n</code></pre><p>Consider <code>1abc2</code>.</p><p>Adding these together produces <code><em>142</em></code>.</p></article>\n\n<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Adding these together produces <code><em>281</em></code>.</p></article>";
+
+    const MARKDOWN: &str = "\\--- Day 1: Trebuchet?! ---\n----------\n\nFor example:\n\n```\n1abc2\npqr3stu8vwx\n```\n\nAdding these together produces *`142`*.\n\n\\--- Part Two ---\n----------\n\nAdding these together produces *`281`*.\n";
+
+    #[test]
+    fn splits_parts() {
+        assert!(part(HTML, 1).unwrap().ends_with("</article>\n\n"));
+        assert!(part(HTML, 2)
+            .unwrap()
+            .starts_with("<article class=\"day-desc\"><h2 id=\"part2\">"));
+        assert!(part(MARKDOWN, 2)
+            .unwrap()
+            .starts_with("\n\\--- Part Two ---"));
+        assert_eq!(part("<article>--- Day 1 ---</article>", 2), None);
+    }
+
+    #[test]
+    fn finds_example_inputs() {
+        assert_eq!(
+            example_input(HTML, 1).as_deref(),
+            Some("1abc2\npqr3stu8vwx\n")
+        );
+        assert_eq!(example_input(HTML, 2), None);
+        assert_eq!(
+            example_input(MARKDOWN, 1).as_deref(),
+            Some("1abc2\npqr3stu8vwx\n")
+        );
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        assert_eq!(example_answer(HTML, 1).as_deref(), Some("142"));
+        assert_eq!(example_answer(HTML, 2).as_deref(), Some("281"));
+        assert_eq!(example_answer(MARKDOWN, 1).as_deref(), Some("142"));
+        assert_eq!(example_answer(MARKDOWN, 2).as_deref(), Some("281"));
+        assert_eq!(example_answer("<article></article>", 1), None);
+    }
+
+    #[test]
+    fn converts_html_to_text() {
+        assert_eq!(
+            html_to_text(
+                "<p>That's <em>not</em> the right answer; 1 &lt; 2 &amp;&amp; 3 &gt; 2</p>"
+            ),
+            "That's not the right answer; 1 < 2 && 3 > 2"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/// Module that checks solutions against the named example cases in `data/{year}/examples/{day}/`.
+///
+/// Every case consists of an input `{name}.txt` and an optional sidecar `{name}.toml` with the expected answers:
+///
+/// ```toml
+/// part_one = 8
+/// part_two = "10"
+///
+/// [params]
+/// steps = 6
+/// ```
+///
+/// The parameters are passed to solutions that take [`Params`].
+///
+/// `build.rs` generates one test per case, which is included by the `solution!` macro.
+use std::{fmt::Display, fs};
+
+use serde::Deserialize;
+
+use crate::template::{get_data_path, params::Params};
+use crate::PuzzleId;
+
+/// An expected answer, written as number or string and compared as text. TOML integers end at `i64::MAX`, so larger
+/// answers are written as strings, e.g. `part_one = "18446744073709551615"`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "RawAnswer")]
+pub struct Answer(pub String);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Number(n) => Self(n.to_string()),
+            RawAnswer::Text(s) => Self(s),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The expected answers of an example case.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Expected {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    #[serde(default)]
+    pub params: Params,
+}
+
+impl Expected {
+    fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+}
+
+/// Reads the input and the expected answers of an example case. A missing sidecar expects nothing.
+///
+/// # Panics
+///
+/// If the input does not exist or the sidecar is malformed.
+#[must_use]
+pub fn read_case(puzzle: PuzzleId, name: &str) -> (String, Expected) {
+    let dir = get_data_path("examples", puzzle, &puzzle.day.to_string());
+
+    let input =
+        fs::read_to_string(dir.join(format!("{name}.txt"))).expect("could not open example file");
+
+    let expected = match fs::read_to_string(dir.join(format!("{name}.toml"))) {
+        Ok(s) => Expected::parse(&s).expect("could not parse expected answers"),
+        Err(_) => Expected::default(),
+    };
+
+    (input, expected)
+}
+
+/// Runs both parts on an example case and compares the results with the expected answers.
+/// Parts without an expected answer are not run.
+///
+/// # Panics
+///
+/// If a result differs from the expected answer.
+pub fn check<A: Display, B: Display>(
+    puzzle: PuzzleId,
+    name: &str,
+    part_one: impl Fn(&str, &Params) -> Option<A>,
+    part_two: impl Fn(&str, &Params) -> Option<B>,
+) {
+    let (input, expected) = read_case(puzzle, name);
+
+    if let Some(answer) = expected.part_one {
+        let result = part_one(&input, &expected.params).map(|r| r.to_string());
+        assert_eq!(
+            result,
+            Some(answer.to_string()),
+            "example \"{name}\", part 1"
+        );
+    }
+
+    if let Some(answer) = expected.part_two {
+        let result = part_two(&input, &expected.params).map(|r| r.to_string());
+        assert_eq!(
+            result,
+            Some(answer.to_string()),
+            "example \"{name}\", part 2"
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Expected, Params};
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected = Expected::parse("part_one = 8\npart_two = \"EZFCHJAB\"\n").unwrap();
+        assert_eq!(expected.part_one, Some(Answer("8".into())));
+        assert_eq!(expected.part_two, Some(Answer("EZFCHJAB".into())));
+        assert_eq!(expected.part_two.unwrap().to_string(), "EZFCHJAB");
+
+        // too large for a TOML integer.
+        assert!(Expected::parse("part_one = 18446744073709551615").is_err());
+        let expected = Expected::parse("part_one = \"18446744073709551615\"").unwrap();
+        assert_eq!(expected.part_one.unwrap().to_string(), u64::MAX.to_string());
+
+        assert_eq!(Expected::parse("").unwrap(), Expected::default());
+        assert_eq!(
+            Expected::parse("part_one = 16\n\n[params]\nsteps = 6\n")
+                .unwrap()
+                .params,
+            Params::from([("steps", 6)])
+        );
+        assert!(Expected::parse("part_one = [1]").is_err());
+    }
+}
@@ -0,0 +1,400 @@
+/// Module that guards the puzzle inputs in `data/{year}/inputs`.
+///
+/// Every download records the checksum and time of the download in `data/{year}/inputs/{day}.toml`,
+/// which is used to detect inputs that were edited by hand.
+///
+/// ```toml
+/// sha256 = "5f2b…"
+/// downloaded_at = "2023-12-01T05:00:03Z"
+/// ```
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Read, Write},
+    path::PathBuf,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::template::{benchmark_history::format_timestamp, commands::download, get_data_path};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse input record: {e}"),
+            Error::IO(e) => write!(f, "could not access input record: {e}"),
+        }
+    }
+}
+
+/// The record of an input download.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Download {
+    /// The SHA-256 checksum of the input as hex string.
+    pub sha256: String,
+    /// The time of the download as UTC timestamp, e.g. `2023-12-01T05:00:03Z`.
+    pub downloaded_at: String,
+}
+
+/// The state of an input file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Missing,
+    Empty,
+    /// The input was not downloaded by the template, so it can not be checked.
+    Unrecorded,
+    Intact,
+    Modified(Modification),
+}
+
+/// The kind of change to an input since it was downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modification {
+    StrippedNewline,
+    LineEndings,
+    Edited,
+}
+
+impl Display for Modification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Modification::StrippedNewline => {
+                write!(f, "lost its trailing newline since the download")
+            }
+            Modification::LineEndings => {
+                write!(f, "has different line endings than the download")
+            }
+            Modification::Edited => write!(f, "was changed since the download"),
+        }
+    }
+}
+
+/// The input a solution is run on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    /// The puzzle input in `data/{year}/inputs`.
+    #[default]
+    Puzzle,
+    /// An example in `data/{year}/examples`, either `NN.txt` or the named example.
+    Example(Option<String>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads the source from the `--input <path|->` and `--example [name]` arguments of a solution binary.
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        if let Some(i) = args.iter().position(|x| x == "--input") {
+            return match args.get(i + 1).map(String::as_str) {
+                Some("-") => Source::Stdin,
+                Some(path) => Source::File(path.into()),
+                None => {
+                    eprintln!(
+                        "Unexpected command-line input. Format: cargo solve 1 --input <path|->"
+                    );
+                    process::exit(1);
+                }
+            };
+        }
+
+        if let Some(i) = args.iter().position(|x| x == "--example") {
+            let name = args.get(i + 1).filter(|x| !x.starts_with('-')).cloned();
+            return Source::Example(name);
+        }
+
+        Source::Puzzle
+    }
+
+    /// Returns the arguments that select this source, see [`Source::from_args`].
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Source::Puzzle => vec![],
+            Source::Example(name) => std::iter::once("--example".to_string())
+                .chain(name.clone())
+                .collect(),
+            Source::File(path) => vec!["--input".into(), path.to_string_lossy().into_owned()],
+            Source::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Whether this is the puzzle input, the only input that answers can be verified and submitted for.
+    #[must_use]
+    pub fn is_puzzle(&self) -> bool {
+        *self == Source::Puzzle
+    }
+
+    /// Describes where the input of a puzzle is read from, e.g. `data/2023/examples/01.txt`.
+    #[must_use]
+    pub fn describe(&self, puzzle: PuzzleId) -> String {
+        match self {
+            Source::Puzzle => get_path(puzzle).display().to_string(),
+            Source::Example(name) => get_example_path(puzzle, name.as_deref())
+                .display()
+                .to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "stdin".into(),
+        }
+    }
+
+    /// Reads the input of a puzzle from this source. Exits with an explanation if it can not be read.
+    #[must_use]
+    pub fn load(&self, puzzle: PuzzleId) -> String {
+        let read = match self {
+            Source::Puzzle => return load(puzzle),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            _ => fs::read_to_string(self.describe(puzzle)),
+        };
+
+        match read {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read input from {}: {e}", self.describe(puzzle));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Returns the path of an example: `NN.txt` without a name, else the named case `NN/{name}.txt` or `NN-{name}.txt`.
+fn get_example_path(puzzle: PuzzleId, name: Option<&str>) -> PathBuf {
+    let day = puzzle.day;
+    match name {
+        None => get_data_path("examples", puzzle, &format!("{day}.txt")),
+        Some(name) => {
+            let case = get_data_path("examples", puzzle, &format!("{day}/{name}.txt"));
+            if case.exists() {
+                case
+            } else {
+                get_data_path("examples", puzzle, &format!("{day}-{name}.txt"))
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("inputs", puzzle, &format!("{}.txt", puzzle.day))
+}
+
+fn get_record_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("inputs", puzzle, &format!("{}.toml", puzzle.day))
+}
+
+/// Records the checksum of a freshly downloaded input.
+pub fn record(puzzle: PuzzleId, input: &str) -> Result<(), Error> {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let download = Download {
+        sha256: checksum(input),
+        downloaded_at: format_timestamp(seconds),
+    };
+
+    let s = toml::to_string(&download).map_err(|e| Error::Parser(e.to_string()))?;
+    fs::write(get_record_path(puzzle), s)?;
+    Ok(())
+}
+
+/// Reads the input of a puzzle and checks it against the recorded download.
+/// A missing input is returned as empty string.
+#[must_use]
+pub fn read(puzzle: PuzzleId) -> (String, Status) {
+    let Ok(input) = fs::read_to_string(get_path(puzzle)) else {
+        return (String::new(), Status::Missing);
+    };
+
+    let download = fs::read_to_string(get_record_path(puzzle))
+        .ok()
+        .and_then(|s| toml::from_str::<Download>(&s).ok());
+
+    let status = check(&input, download.as_ref());
+    (input, status)
+}
+
+/// Reads the input of a puzzle for a solution run.
+/// Missing inputs are downloaded if the user agrees, otherwise the program exits with an explanation.
+#[must_use]
+pub fn load(puzzle: PuzzleId) -> String {
+    let path = get_path(puzzle);
+
+    let (input, status) = match read(puzzle) {
+        (_, Status::Missing | Status::Empty) if confirm_download(puzzle) => {
+            download::handle(puzzle);
+            read(puzzle)
+        }
+        result => result,
+    };
+
+    match status {
+        Status::Missing => {
+            eprintln!(
+                "Missing input file \"{}\". Run `cargo download {}` to download it.",
+                path.display(),
+                puzzle.day.into_inner()
+            );
+            process::exit(1);
+        }
+        Status::Empty => {
+            eprintln!(
+                "Input file \"{}\" is empty. Run `cargo download {}` to download it.",
+                path.display(),
+                puzzle.day.into_inner()
+            );
+            process::exit(1);
+        }
+        Status::Modified(modification) => warn(puzzle, modification),
+        Status::Unrecorded | Status::Intact => {}
+    }
+
+    input
+}
+
+/// Prints a warning about an input that was edited by hand.
+pub fn warn(puzzle: PuzzleId, modification: Modification) {
+    eprintln!(
+        "Warning: input file \"{}\" {modification}. Run `cargo download {}` to restore it.",
+        get_path(puzzle).display(),
+        puzzle.day.into_inner()
+    );
+}
+
+fn confirm_download(puzzle: PuzzleId) -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+
+    print!("The input of {puzzle} is missing or empty. Download it now? [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
+
+fn check(input: &str, download: Option<&Download>) -> Status {
+    if input.trim().is_empty() {
+        return Status::Empty;
+    }
+
+    let Some(download) = download else {
+        return Status::Unrecorded;
+    };
+
+    if checksum(input) == download.sha256 {
+        Status::Intact
+    } else if checksum(&format!("{input}\n")) == download.sha256 {
+        Status::Modified(Modification::StrippedNewline)
+    } else if checksum(&input.replace("\r\n", "\n")) == download.sha256 {
+        Status::Modified(Modification::LineEndings)
+    } else {
+        Status::Modified(Modification::Edited)
+    }
+}
+
+fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, checksum, Download, Modification, Source, Status};
+
+    fn download(input: &str) -> Download {
+        Download {
+            sha256: checksum(input),
+            downloaded_at: "2023-12-01T05:00:03Z".into(),
+        }
+    }
+
+    #[test]
+    fn computes_checksums() {
+        assert_eq!(
+            checksum("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn detects_empty_inputs() {
+        assert_eq!(check("", None), Status::Empty);
+        assert_eq!(check("\n", Some(&download("1\n"))), Status::Empty);
+        assert_eq!(check("1\n", None), Status::Unrecorded);
+    }
+
+    #[test]
+    fn detects_modifications() {
+        let original = download("1abc2\npqr3stu8vwx\n");
+        assert_eq!(
+            check("1abc2\npqr3stu8vwx\n", Some(&original)),
+            Status::Intact
+        );
+        assert_eq!(
+            check("1abc2\npqr3stu8vwx", Some(&original)),
+            Status::Modified(Modification::StrippedNewline)
+        );
+        assert_eq!(
+            check("1abc2\r\npqr3stu8vwx\r\n", Some(&original)),
+            Status::Modified(Modification::LineEndings)
+        );
+        assert_eq!(
+            check("1abc2\npqr3stu8vwy\n", Some(&original)),
+            Status::Modified(Modification::Edited)
+        );
+    }
+
+    #[test]
+    fn parses_sources() {
+        let args = |s: &str| -> Vec<String> { s.split_whitespace().map(Into::into).collect() };
+
+        assert_eq!(Source::from_args(&args("bin --time")), Source::Puzzle);
+        assert_eq!(Source::from_args(&args("bin --input -")), Source::Stdin);
+        assert_eq!(
+            Source::from_args(&args("bin --input stress.txt --time")),
+            Source::File("stress.txt".into())
+        );
+        assert_eq!(
+            Source::from_args(&args("bin --example --time")),
+            Source::Example(None)
+        );
+        assert_eq!(
+            Source::from_args(&args("bin --example larger")),
+            Source::Example(Some("larger".into()))
+        );
+
+        for source in [
+            Source::Puzzle,
+            Source::Stdin,
+            Source::File("stress.txt".into()),
+            Source::Example(None),
+            Source::Example(Some("2".into())),
+        ] {
+            let mut args = source.to_args();
+            args.insert(0, "bin".into());
+            assert_eq!(Source::from_args(&args), source);
+        }
+    }
+}
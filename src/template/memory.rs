@@ -0,0 +1,185 @@
+/// Module that counts the heap allocations of solution parts, see `--mem`.
+///
+/// [`CountingAllocator`] is the global allocator of every binary that links this library. It forwards to the system
+/// allocator and only counts while a measurement is active, so runs without `--mem` only pay for one atomic load.
+/// The counters are global, which includes allocations on threads spawned by a part (e.g. by rayon), but also those of
+/// anything else running at the same time. Parts are therefore measured one after another.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated minus bytes freed since the start of the measurement. Negative if memory that was allocated before
+/// the measurement is freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+/// The allocations made while running a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemStats {
+    /// The maximum number of bytes that were allocated at the same time, on top of what was allocated at the start.
+    pub peak_bytes: u64,
+    /// The number of allocations, including reallocations.
+    pub allocations: u64,
+    /// The total number of bytes requested by all allocations.
+    pub allocated_bytes: u64,
+}
+
+impl MemStats {
+    fn read() -> Self {
+        Self {
+            peak_bytes: PEAK.load(Ordering::SeqCst).unsigned_abs() as u64,
+            allocations: ALLOCATIONS.load(Ordering::SeqCst),
+            allocated_bytes: ALLOCATED.load(Ordering::SeqCst),
+        }
+    }
+}
+
+/// Runs `f` and counts the allocations it makes.
+///
+/// Returns no stats if another measurement is still active, e.g. of a part that timed out and keeps running.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    if ACTIVE
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return (f(), None);
+    }
+
+    // ends the measurement even if `f` panics.
+    struct Measurement;
+    impl Drop for Measurement {
+        fn drop(&mut self) {
+            ACTIVE.store(false, Ordering::SeqCst);
+        }
+    }
+
+    CURRENT.store(0, Ordering::SeqCst);
+    PEAK.store(0, Ordering::SeqCst);
+    ALLOCATIONS.store(0, Ordering::SeqCst);
+    ALLOCATED.store(0, Ordering::SeqCst);
+
+    let measurement = Measurement;
+    let result = f();
+    let stats = MemStats::read();
+    drop(measurement);
+
+    (result, Some(stats))
+}
+
+/// Formats a number of bytes with binary units, e.g. `1.5 MiB`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in units {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    if unit == "B" {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {unit}")
+    }
+}
+
+/// A global allocator that forwards to [`System`] and counts allocations while a measurement is active.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    #[allow(clippy::cast_possible_wrap)]
+    fn record_alloc(size: usize) {
+        let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ACTIVE.load(Ordering::Relaxed) {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ACTIVE.load(Ordering::Relaxed) {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ACTIVE.load(Ordering::Relaxed) {
+            Self::record_dealloc(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ACTIVE.load(Ordering::Relaxed) {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure};
+
+    #[test]
+    fn counts_allocations() {
+        let (len, stats) = measure(|| {
+            let mut buffers = vec![];
+            for _ in 0..4 {
+                buffers.push(std::hint::black_box(vec![0_u8; 1 << 20]));
+            }
+            drop(buffers);
+            let small = std::hint::black_box(vec![0_u8; 1024]);
+            small.len()
+        });
+        assert_eq!(len, 1024);
+
+        // other tests may allocate at the same time, so only lower bounds hold.
+        let stats = stats.unwrap();
+        assert!(stats.peak_bytes >= 4 << 20);
+        assert!(stats.allocations >= 6);
+        assert!(stats.allocated_bytes >= (4 << 20) + 1024);
+
+        let (inner, outer) = measure(|| measure(|| ()).1);
+        assert_eq!(inner, None);
+        assert!(outer.is_some());
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+    }
+}
@@ -0,0 +1,252 @@
+/// Module that provides a local stand-in for the advent of code website, used to test the network flows offline.
+///
+/// The server answers the same routes as advent of code with the fixtures in `fixtures/`:
+/// `GET /{year}/day/{day}`, `GET /{year}/day/{day}/input` and `POST /{year}/day/{day}/answer`.
+/// Requests without the expected session cookie are rejected, days without a fixture are not unlocked.
+use std::{
+    collections::HashSet,
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use crate::{day, year, PuzzleId};
+
+const PUZZLE_PAGE: &str = include_str!("fixtures/puzzle.html");
+const INPUT: &str = include_str!("fixtures/input.txt");
+const ANSWER_CORRECT: &str = include_str!("fixtures/answer_correct.html");
+const ANSWER_TOO_HIGH: &str = include_str!("fixtures/answer_too_high.html");
+const ANSWER_TOO_LOW: &str = include_str!("fixtures/answer_too_low.html");
+const ANSWER_WRONG: &str = include_str!("fixtures/answer_wrong.html");
+const ANSWER_RATE_LIMITED: &str = include_str!("fixtures/answer_rate_limited.html");
+const ANSWER_ALREADY_COMPLETED: &str = include_str!("fixtures/answer_already_completed.html");
+
+/// A puzzle that is served by the mock server.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub puzzle: PuzzleId,
+    pub page: String,
+    pub input: String,
+    /// The accepted answers of part one and two.
+    pub answers: [String; 2],
+}
+
+impl Fixture {
+    /// The first puzzle of 2023, with the example as input.
+    #[must_use]
+    pub fn day_one() -> Self {
+        Self {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            page: PUZZLE_PAGE.into(),
+            input: INPUT.into(),
+            answers: ["142".into(), "281".into()],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The parts that were answered correctly.
+    solved: HashSet<(PuzzleId, u8)>,
+    /// Every request as `METHOD /path`, in order of arrival.
+    requests: Vec<String>,
+}
+
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    rate_limited: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    /// Starts a server on a free local port that accepts `session` as session cookie.
+    #[must_use]
+    pub fn start(session: &str, fixtures: Vec<Fixture>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = Self {
+            addr,
+            state: Arc::default(),
+            rate_limited: Arc::default(),
+            shutdown: Arc::default(),
+        };
+
+        let handler = Handler {
+            session: session.to_string(),
+            fixtures,
+            state: server.state.clone(),
+            rate_limited: server.rate_limited.clone(),
+        };
+        let shutdown = server.shutdown.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    handler.handle(stream);
+                }
+            }
+        });
+
+        server
+    }
+
+    /// The base URL of the server, to be used as `AOC_BASE_URL`.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Makes the server reject every submission as too recent.
+    pub fn set_rate_limited(&self, rate_limited: bool) {
+        self.rate_limited.store(rate_limited, Ordering::SeqCst);
+    }
+
+    /// Returns the requests received so far as `METHOD /path`.
+    #[must_use]
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wake up the listener so that it notices the shutdown.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+struct Handler {
+    session: String,
+    fixtures: Vec<Fixture>,
+    state: Arc<Mutex<State>>,
+    rate_limited: Arc<AtomicBool>,
+}
+
+impl Handler {
+    fn handle(&self, stream: TcpStream) {
+        let mut reader = BufReader::new(&stream);
+
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).is_err() {
+            return;
+        }
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+
+        let mut cookie = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                match name.trim().to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.trim().to_string(),
+                    "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .push(format!("{method} {path}"));
+
+        let (status, response) = if cookie == format!("session={}", self.session) {
+            self.route(&method, &path, &String::from_utf8_lossy(&body))
+        } else {
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".into(),
+            )
+        };
+
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            _ => "Not Found",
+        };
+
+        let _ = write!(
+            &stream,
+            "HTTP/1.1 {status} {reason}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+            response.len()
+        );
+    }
+
+    fn route(&self, method: &str, path: &str, body: &str) -> (u16, String) {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        let fixture = match segments.as_slice() {
+            [year, "day", day, ..] => self.fixtures.iter().find(|f| {
+                f.puzzle.year.to_string() == *year && f.puzzle.day.into_inner().to_string() == *day
+            }),
+            _ => None,
+        };
+
+        let not_found = (404, "404 Not Found\n".to_string());
+        let Some(fixture) = fixture else {
+            return not_found;
+        };
+
+        match (method, &segments[3..]) {
+            ("GET", []) => (200, fixture.page.clone()),
+            ("GET", ["input"]) => (200, fixture.input.clone()),
+            ("POST", ["answer"]) => (200, self.answer(fixture, body).into()),
+            _ => not_found,
+        }
+    }
+
+    fn answer(&self, fixture: &Fixture, body: &str) -> &'static str {
+        let field = |name: &str| {
+            body.split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+                .unwrap_or_default()
+        };
+
+        let part: u8 = field("level").parse().unwrap_or(1);
+        let answer = field("answer");
+
+        if self.rate_limited.load(Ordering::SeqCst) {
+            return ANSWER_RATE_LIMITED;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if state.solved.contains(&(fixture.puzzle, part)) {
+            return ANSWER_ALREADY_COMPLETED;
+        }
+
+        let expected = &fixture.answers[usize::from(part == 2)];
+        if answer == *expected {
+            state.solved.insert((fixture.puzzle, part));
+            return ANSWER_CORRECT;
+        }
+
+        match (answer.parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(a), Ok(e)) if a > e => ANSWER_TOO_HIGH,
+            (Ok(a), Ok(e)) if a < e => ANSWER_TOO_LOW,
+            _ => ANSWER_WRONG,
+        }
+    }
+}
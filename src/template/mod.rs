@@ -1,48 +1,144 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::{env, fs, path::PathBuf};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod description;
+pub mod example_cases;
+pub mod inputs;
+pub mod memory;
+#[cfg(feature = "test_lib")]
+pub mod mock_server;
+pub mod params;
 pub mod readme_benchmarks;
+pub mod report;
 pub mod runner;
+pub mod submission;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a data file for a puzzle, e.g. `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, file_name: &str) -> PathBuf {
+    PathBuf::from("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(file_name)
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(get_data_path(
+        folder,
+        puzzle,
+        &format!("{}.txt", puzzle.day),
+    ));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+    let filepath = cwd.join(get_data_path(
+        folder,
+        puzzle,
+        &format!("{}-{part}.txt", puzzle.day),
+    ));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constant `PUZZLE`, registers the solution as `SOLUTION`, sets up the input and runner for each part
+/// and includes the tests of the named example cases.
+///
+/// With `solution!(2023, 21, params)`, both parts take the [`params::Params`] of the run as second argument.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    ($year:expr, $day:expr, params) => {
+        advent_of_code::solution!(
+            @solution $year,
+            $day,
+            |input, params| part_one(input, params),
+            |input, params| part_two(input, params)
+        );
+    };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(
+            @solution $year,
+            $day,
+            |input, _| part_one(input),
+            |input, _| part_two(input)
+        );
+    };
+    (@solution $year:expr, $day:expr, $part_one:expr, $part_two:expr) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId =
+            advent_of_code::PuzzleId::new(advent_of_code::year!($year), advent_of_code::day!($day));
+
+        /// The registry entry for this puzzle, see `advent_of_code::template::runner::Solution`.
+        pub const SOLUTION: advent_of_code::template::runner::Solution =
+            advent_of_code::template::runner::Solution {
+                puzzle: PUZZLE,
+                run: |input, options| {
+                    use advent_of_code::template::runner::*;
+                    // parts run on their own threads, see `run_part`.
+                    let input: std::sync::Arc<str> = input.into();
+                    let mut results = vec![];
+                    if options.runs_part(1) {
+                        let params = options.params.clone();
+                        results.push(run_part(
+                            move |input| ($part_one)(input, &params),
+                            std::sync::Arc::clone(&input),
+                            PUZZLE,
+                            1,
+                            options,
+                        ));
+                    }
+                    if options.runs_part(2) {
+                        let params = options.params.clone();
+                        results.push(run_part(
+                            move |input| ($part_two)(input, &params),
+                            input,
+                            PUZZLE,
+                            2,
+                            options,
+                        ));
+                    }
+                    results
+                },
+            };
 
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::runner::main(&SOLUTION);
+        }
+
+        /// Runs both parts on a named example case, called by the generated tests in `example_cases`.
+        #[cfg(test)]
+        #[allow(dead_code)]
+        fn check_example(name: &str) {
+            advent_of_code::template::example_cases::check(PUZZLE, name, $part_one, $part_two);
+        }
+
+        /// One test per named example case in `data/{year}/examples/{day}/`, generated by `build.rs`.
+        #[cfg(test)]
+        mod example_cases {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                stringify!($year),
+                "-",
+                stringify!($day),
+                ".rs"
+            ));
         }
     };
 }
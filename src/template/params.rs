@@ -0,0 +1,159 @@
+/// Module for the parameters of solutions, i.e. puzzle constants like step counts that differ between the examples and
+/// the puzzle input.
+///
+/// Solutions opt in via `solution!(2023, 21, params)`, after which both parts take the parameters as second argument:
+///
+/// ```ignore
+/// pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+///     let steps = params.get_or("steps", 64);
+///     // ...
+/// }
+/// ```
+///
+/// Parameters are passed on the command line with `--param steps=6`, or in the `[params]` table of an example case.
+use std::{collections::BTreeMap, fmt::Display, process, str::FromStr};
+
+use serde::Deserialize;
+
+/// Named parameters of a solution. Values are kept as strings and parsed when they are read.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(from = "BTreeMap<String, toml::Value>")]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Reads a parameter, returning `None` if it is not set.
+    ///
+    /// # Panics
+    ///
+    /// If the value can not be parsed as `T`.
+    #[must_use]
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        let value = self.0.get(key)?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => panic!("invalid value for parameter {key}: {value}"),
+        }
+    }
+
+    /// Reads a parameter, returning `default` if it is not set.
+    ///
+    /// # Panics
+    ///
+    /// If the value can not be parsed as `T`.
+    #[must_use]
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Parses a `key=value` pair, as passed to `--param`.
+    ///
+    /// # Errors
+    ///
+    /// If the pair has no `=` or an empty key.
+    pub fn parse_pair(s: &str) -> Result<(String, String), String> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!("expected key=value, got \"{s}\"")),
+        }
+    }
+
+    /// Reads all `--param key=value` arguments of a solution binary.
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        args.iter()
+            .zip(args.iter().skip(1))
+            .filter(|(name, _)| *name == "--param")
+            .map(|(_, pair)| {
+                Self::parse_pair(pair).unwrap_or_else(|e| {
+                    eprintln!(
+                        "Unexpected command-line input: {e}. Format: cargo solve 1 --param steps=6"
+                    );
+                    process::exit(1);
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the arguments that pass these parameters, see [`Params::from_args`].
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .flat_map(|(key, value)| ["--param".to_string(), format!("{key}={value}")])
+            .collect()
+    }
+}
+
+impl<K: Into<String>, V: Display> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Into<String>, V: Display, const N: usize> From<[(K, V); N]> for Params {
+    fn from(pairs: [(K, V); N]) -> Self {
+        pairs.into_iter().collect()
+    }
+}
+
+impl From<BTreeMap<String, toml::Value>> for Params {
+    fn from(table: BTreeMap<String, toml::Value>) -> Self {
+        table
+            .into_iter()
+            .map(|(key, value)| match value {
+                toml::Value::String(s) => (key, s),
+                value => (key, value.to_string()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Params;
+
+    #[test]
+    fn reads_typed_values() {
+        let params = Params::from([("steps", "6"), ("min", "2e14")]);
+        assert_eq!(params.get::<usize>("steps"), Some(6));
+        assert_eq!(params.get_or("min", 0.0), 2e14);
+        assert_eq!(params.get_or("cycles", 1_000_000_000), 1_000_000_000);
+        assert_eq!(params.get::<u32>("factor"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value for parameter steps: six")]
+    fn rejects_invalid_values() {
+        let _ = Params::from([("steps", "six")]).get::<usize>("steps");
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(
+            Params::parse_pair(" steps = 6"),
+            Ok(("steps".into(), "6".into()))
+        );
+        assert!(Params::parse_pair("steps").is_err());
+        assert!(Params::parse_pair("=6").is_err());
+
+        let params = Params::from([("max", "27"), ("min", "7")]);
+        let mut args = params.to_args();
+        assert_eq!(args, ["--param", "max=27", "--param", "min=7"]);
+        args.insert(0, "bin".into());
+        assert_eq!(Params::from_args(&args), params);
+    }
+
+    #[test]
+    fn deserializes_tables() {
+        let params: Params = toml::from_str("steps = 6\nmin = 7.5\nname = \"x\"").unwrap();
+        assert_eq!(params.get::<usize>("steps"), Some(6));
+        assert_eq!(params.get::<f64>("min"), Some(7.5));
+        assert_eq!(params.get::<String>("name").as_deref(), Some("x"));
+    }
+}
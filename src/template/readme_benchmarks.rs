@@ -2,7 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use regex::Regex;
+
+use crate::PuzzleId;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,10 +22,12 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// The larger peak memory of both parts, only shown if the allocations were counted.
+    pub peak_memory: Option<String>,
 }
 
 pub struct TablePosition {
@@ -32,8 +36,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -60,24 +64,47 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 
 fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
+    let has_memory = timings.iter().any(|timing| timing.peak_memory.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+    if has_memory {
+        lines.push("| Day | Part 1 | Part 2 | Peak memory |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // only label rows with their year if the table spans more than one year.
+    let is_multi_year = timings
+        .iter()
+        .any(|timing| timing.puzzle.year != timings[0].puzzle.year);
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+        let path = get_path_for_bin(timing.puzzle);
+        let label = if is_multi_year {
+            format!(
+                "{} Day {}",
+                timing.puzzle.year,
+                timing.puzzle.day.into_inner()
+            )
+        } else {
+            format!("Day {}", timing.puzzle.day.into_inner())
+        };
+        let mut line = format!(
+            "| [{}]({}) | `{}` | `{}` |",
+            label,
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+        if has_memory {
+            line.push_str(&format!(
+                " `{}` |",
+                timing.peak_memory.unwrap_or_else(|| "-".into())
+            ));
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -94,9 +121,72 @@ fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> R
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Reads the rows of the existing table, so that the timings of a partial run can be merged into it.
+fn parse_table(table: &str) -> Vec<Timings> {
+    let row = Regex::new(
+        r"(?m)^\| \[[^\]]*\]\(\./src/bin/([^)]+)\.rs\) \| `([^`]*)` \| `([^`]*)` \|(?: `([^`]*)` \|)?$",
+    )
+    .unwrap();
+    let timing = |s: &str| (s != "-").then(|| s.to_string());
+
+    row.captures_iter(table)
+        .filter_map(|c| {
+            let part_1 = timing(&c[2]);
+            let part_2 = timing(&c[3]);
+            let total_nanos = [&part_1, &part_2]
+                .into_iter()
+                .flatten()
+                .filter_map(|t| parse_duration(t))
+                .sum();
+
+            Some(Timings {
+                puzzle: c[1].parse().ok()?,
+                part_1,
+                part_2,
+                total_nanos,
+                peak_memory: c.get(4).and_then(|m| timing(m.as_str())),
+            })
+        })
+        .collect()
+}
+
+/// Parses a duration as formatted by `{:.1?}`, e.g. `74.1µs`, into nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    let units = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+    units.iter().find_map(|(unit, factor)| {
+        let value: f64 = s.strip_suffix(unit)?.parse().ok()?;
+        Some(value * factor)
+    })
+}
+
+/// Replaces the rows of the existing table with the rows of the days that ran, keeping the other rows.
+fn merge(existing: Vec<Timings>, mut timings: Vec<Timings>) -> Vec<Timings> {
+    let mut merged: Vec<Timings> = existing
+        .into_iter()
+        .filter(|e| !timings.iter().any(|t| t.puzzle == e.puzzle))
+        .collect();
+    merged.append(&mut timings);
+    merged.sort_by_key(|t| t.puzzle);
+    merged
+}
+
+/// Writes the timings to the table in the readme. With `partial`, the timings are merged into the existing table,
+/// otherwise the table is replaced. The total is computed over all rows of the resulting table.
+pub fn update(timings: Vec<Timings>, partial: bool) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    let timings = if partial {
+        let position = locate_table(&readme)?;
+        merge(
+            parse_table(&readme[position.pos_start..position.pos_end]),
+            timings,
+        )
+    } else {
+        timings
+    };
+    let total_millis = timings.iter().map(|t| t.total_nanos).sum::<f64>() / 1_000_000_f64;
+
     update_content(&mut readme, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
@@ -104,28 +194,31 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{merge, parse_duration, parse_table, update_content, Timings, MARKER};
+    use crate::{day, year, PuzzleId};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
+                peak_memory: None,
             },
             Timings {
-                day: day!(2),
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
+                peak_memory: None,
             },
             Timings {
-                day: day!(4),
+                puzzle: PuzzleId::new(year!(2023), day!(4)),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
+                peak_memory: None,
             },
         ]
     }
@@ -172,9 +265,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +276,58 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn merges_partial_runs() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+
+        let existing = parse_table(&s);
+        assert_eq!(existing.len(), 3);
+        assert_eq!(existing[1].part_2.as_deref(), Some("40ms"));
+        assert_eq!(existing[1].total_nanos, 7e7);
+
+        let rerun = Timings {
+            puzzle: PuzzleId::new(year!(2023), day!(2)),
+            part_1: Some("1.5µs".into()),
+            part_2: None,
+            total_nanos: 1500.0,
+            peak_memory: None,
+        };
+        let added = Timings {
+            puzzle: PuzzleId::new(year!(2023), day!(3)),
+            ..rerun.clone()
+        };
+        let merged = merge(existing, vec![added, rerun]);
+        let days: Vec<u8> = merged.iter().map(|t| t.puzzle.day.into_inner()).collect();
+        assert_eq!(days, [1, 2, 3, 4]);
+        assert_eq!(merged[1].part_1.as_deref(), Some("1.5µs"));
+        assert_eq!(merged[1].part_2, None);
+    }
+
+    #[test]
+    fn shows_peak_memory() {
+        let mut timings = get_mock_timings();
+        timings[1].peak_memory = Some("1.5 MiB".into());
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Peak memory |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` | `-` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | `1.5 MiB` |"));
+
+        let existing = parse_table(&s);
+        assert_eq!(existing.len(), 3);
+        assert_eq!(existing[0].peak_memory, None);
+        assert_eq!(existing[1].peak_memory.as_deref(), Some("1.5 MiB"));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("500.0ns"), Some(500.0));
+        assert_eq!(parse_duration("74.1µs"), Some(74_100.0));
+        assert_eq!(parse_duration("12.5ms"), Some(12_500_000.0));
+        assert_eq!(parse_duration("1.2s"), Some(1_200_000_000.0));
+        assert_eq!(parse_duration("-"), None);
+    }
 }
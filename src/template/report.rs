@@ -0,0 +1,277 @@
+/// Machine-readable output of solution runs.
+/// Each part of a solution is reported as one flat record, so the output can be consumed by scripts without parsing the text output.
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::{PartResult, Status};
+
+/// The output format of `solve` and `all`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = FormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(FormatFromStrError),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Csv => "csv",
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`Format`].
+#[derive(Debug)]
+pub struct FormatFromStrError;
+
+impl std::error::Error for FormatFromStrError {}
+
+impl Display for FormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text`, `json` or `csv`")
+    }
+}
+
+/// A single part of a solution run. Durations are reported in nanoseconds, memory in bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub samples: u64,
+    pub min_ns: u64,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub stddev_ns: u64,
+    pub outliers: u64,
+    /// The panic message or timeout of a part that failed to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The allocations of a part, only reported with `--mem`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+}
+
+impl From<&PartResult> for Record {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(result: &PartResult) -> Self {
+        let stats = &result.stats;
+        let memory = result.memory.as_ref();
+        Self {
+            year: result.puzzle.year.into_inner(),
+            day: result.puzzle.day.into_inner(),
+            part: result.part,
+            answer: result.answer.clone(),
+            status: result.status,
+            samples: stats.samples as u64,
+            min_ns: stats.min.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+            outliers: stats.outliers as u64,
+            error: result.error.clone(),
+            peak_bytes: memory.map(|m| m.peak_bytes),
+            allocations: memory.map(|m| m.allocations),
+            allocated_bytes: memory.map(|m| m.allocated_bytes),
+        }
+    }
+}
+
+const CSV_HEADER: &str =
+    "year,day,part,answer,status,samples,min_ns,mean_ns,median_ns,p95_ns,stddev_ns,outliers";
+/// Columns that are appended if the allocations were counted.
+const CSV_MEMORY_HEADER: &str = ",peak_bytes,allocations,allocated_bytes";
+
+/// Renders a list of results in a machine-readable format. Returns [`None`] for [`Format::Text`], which is printed while running.
+#[must_use]
+pub fn render(results: &[PartResult], format: Format) -> Option<String> {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+
+    match format {
+        Format::Text => None,
+        Format::Json => Some(serde_json::to_string_pretty(&records).unwrap()),
+        Format::Csv => Some(render_csv(&records)),
+    }
+}
+
+fn render_csv(records: &[Record]) -> String {
+    let has_memory = records.iter().any(|r| r.peak_bytes.is_some());
+    let mut lines = vec![if has_memory {
+        format!("{CSV_HEADER}{CSV_MEMORY_HEADER}")
+    } else {
+        CSV_HEADER.to_string()
+    }];
+
+    for record in records {
+        let mut line = format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            escape_csv(record.answer.as_deref().unwrap_or_default()),
+            record.status.as_str(),
+            record.samples,
+            record.min_ns,
+            record.mean_ns,
+            record.median_ns,
+            record.p95_ns,
+            record.stddev_ns,
+            record.outliers
+        );
+        if has_memory {
+            let field = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+            line.push_str(&format!(
+                ",{},{},{}",
+                field(record.peak_bytes),
+                field(record.allocations),
+                field(record.allocated_bytes)
+            ));
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render, Format};
+    use crate::template::memory::MemStats;
+    use crate::template::runner::{PartResult, Stats, Status};
+    use crate::{day, year, PuzzleId};
+
+    fn get_mock_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                part: 1,
+                answer: Some("142".into()),
+                status: Status::Solved,
+                stats: Stats::from_samples(&[Duration::from_nanos(100), Duration::from_nanos(300)]),
+                error: None,
+                memory: None,
+            },
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                part: 2,
+                answer: None,
+                status: Status::Unsolved,
+                stats: Stats::from_samples(&[Duration::from_nanos(50)]),
+                error: None,
+                memory: None,
+            },
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                part: 1,
+                answer: None,
+                status: Status::Panicked,
+                stats: Stats::default(),
+                error: Some("index out of bounds".into()),
+                memory: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn text_is_not_rendered() {
+        assert_eq!(render(&get_mock_results(), Format::Text), None);
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = render(&get_mock_results(), Format::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["answer"], "142");
+        assert_eq!(value[0]["status"], "solved");
+        assert_eq!(value[0]["mean_ns"], 200);
+        assert_eq!(value[0]["stddev_ns"], 100);
+        assert_eq!(value[1]["answer"], serde_json::Value::Null);
+        assert_eq!(value[1]["status"], "unsolved");
+        assert!(value[1].get("error").is_none());
+        assert_eq!(value[2]["status"], "panicked");
+        assert_eq!(value[2]["error"], "index out of bounds");
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = render(&get_mock_results(), Format::Csv).unwrap();
+        let expected = [
+            "year,day,part,answer,status,samples,min_ns,mean_ns,median_ns,p95_ns,stddev_ns,outliers",
+            "2023,1,1,142,solved,2,100,200,200,300,100,0",
+            "2023,1,2,,unsolved,1,50,50,50,50,0,0",
+            "2023,2,1,,panicked,0,0,0,0,0,0,0",
+        ]
+        .join("\n");
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn escapes_csv_fields() {
+        let mut results = get_mock_results();
+        results[0].answer = Some("a,\"b\"\nc".into());
+        let csv = render(&results, Format::Csv).unwrap();
+        assert!(csv.contains("2023,1,1,\"a,\"\"b\"\"\nc\",solved"));
+    }
+
+    #[test]
+    fn renders_memory() {
+        let mut results = get_mock_results();
+        results[0].memory = Some(MemStats {
+            peak_bytes: 2048,
+            allocations: 3,
+            allocated_bytes: 4096,
+        });
+
+        let json = render(&results, Format::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["peak_bytes"], 2048);
+        assert_eq!(value[0]["allocations"], 3);
+        assert!(value[1].get("peak_bytes").is_none());
+
+        let csv = render(&results, Format::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",outliers,peak_bytes,allocations,allocated_bytes"));
+        assert_eq!(
+            lines[1],
+            "2023,1,1,142,solved,2,100,200,200,300,100,0,2048,3,4096"
+        );
+        assert_eq!(lines[2], "2023,1,2,,unsolved,1,50,50,50,50,0,0,,,");
+    }
+}
@@ -1,63 +1,541 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::{
+    answers::{Answers, PartAnswers, Rejection, Verdict},
+    aoc_cli, aoc_client,
+    inputs::Source,
+    memory::{self, MemStats},
+    params::Params,
+    report::{self, Format},
+    submission::Outcome,
+    ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::{mpsc, Arc};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// A solution that has been registered via the [`crate::solution`] macro.
+///
+/// Every solution binary exposes its entry as `SOLUTION`, which allows calling both parts in-process.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: fn(&str, &RunOptions) -> Vec<PartResult>,
+}
+
+/// Options that control how a solution is run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    /// Whether answers are checked against `data/{year}/answers`.
+    pub verify: bool,
+    pub submit: Option<u8>,
+    pub format: Format,
+    pub bench: BenchConfig,
+    /// The input the solution is run on. Only answers for the puzzle input are verified and submitted.
+    pub input: Source,
+    /// Runs only this part if set.
+    pub part: Option<u8>,
+    pub params: Params,
+    /// Wall-clock limit for every run of a part, benchmark runs included.
+    pub timeout: Option<Duration>,
+    /// Whether the allocations of the first run of a part are counted, see [`memory`].
+    pub mem: bool,
+}
+
+impl RunOptions {
+    /// Reads the options from the command-line arguments passed to a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let defaults = BenchConfig::default();
+        let input = Source::from_args(&args);
+
+        let mut submit = parse_arg(&args, "--submit", "1");
+        if submit.is_some() && !input.is_puzzle() {
+            eprintln!("Not submitting, answers are only submitted for the puzzle input.");
+            submit = None;
+        }
+
+        Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            verify: input.is_puzzle(),
+            submit,
+            format: parse_arg(&args, "--format", "json").unwrap_or_default(),
+            bench: BenchConfig {
+                warm_up: parse_arg(&args, "--warm-up", "100")
+                    .map_or(defaults.warm_up, Duration::from_millis),
+                time_budget: parse_arg(&args, "--bench-time", "1000")
+                    .map_or(defaults.time_budget, Duration::from_millis),
+            },
+            input,
+            part: parse_arg::<String>(&args, "--part", "1").map(|part| {
+                parse_part(&part).unwrap_or_else(|e| {
+                    eprintln!("Unexpected command-line input: {e}.");
+                    process::exit(1);
+                })
+            }),
+            params: Params::from_args(&args),
+            timeout: parse_arg(&args, "--timeout", "10000").map(Duration::from_millis),
+            mem: args.iter().any(|x| x == "--mem"),
+        }
+    }
+
+    /// Whether a part is run, i.e. no part or this part was selected.
+    #[must_use]
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+/// Parses the part selected with `--part`.
+///
+/// # Errors
+///
+/// If the part is neither `1` nor `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected part 1 or 2, got \"{s}\"")),
+    }
+}
+
+/// The entry point of a solution binary: runs both parts on the input selected by the command-line arguments.
+pub fn main(solution: &Solution) {
+    let options = RunOptions::from_args();
+    let input = options.input.load(solution.puzzle);
+
+    if options.format == Format::Text {
+        println!(
+            "{ANSI_ITALIC}Input: {}{ANSI_RESET}",
+            options.input.describe(solution.puzzle)
+        );
+    }
+
+    let results = (solution.run)(&input, &options);
+    if let Some(report) = report::render(&results, options.format) {
+        println!("{report}");
+    }
+}
+
+/// Parses the value following `name` in `args`. Exits with a usage hint if the value is missing or invalid.
+fn parse_arg<T: FromStr>(args: &[String], name: &str, example: &str) -> Option<T> {
+    let i = args.iter().position(|x| x == name)?;
+    let Some(Ok(value)) = args.get(i + 1).map(|x| x.parse::<T>()) else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 {name} {example}");
+        process::exit(1);
+    };
+    Some(value)
+}
+
+/// Settings for benchmarking a part, see [`bench`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running the part before measurements are taken.
+    pub warm_up: Duration,
+    /// Approximate time spent taking measurements.
+    pub time_budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(100),
+            time_budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Whether a part produced an answer, and whether it matches the accepted answer if one is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Correct,
+    Incorrect,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    /// The name of the status in machine-readable output.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Correct => "correct",
+            Status::Incorrect => "incorrect",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Summary statistics over the measured execution times of a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: u128,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Half-width of the 95% confidence interval of the mean.
+    pub ci95: Duration,
+    /// Number of samples outside of Tukey's fences, i.e. more than 1.5 IQR below the first or above the third quartile.
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty list of measurements.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(timers: &[Duration]) -> Self {
+        let mut sorted: Vec<u128> = timers.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let samples = sorted.len() as u128;
+        let mean = sorted.iter().sum::<u128>() / samples;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        let variance = sorted
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / samples as f64;
+        let stddev = variance.sqrt();
+
+        let q1 = percentile(&sorted, 25) as f64;
+        let q3 = percentile(&sorted, 75) as f64;
+        let iqr = q3 - q1;
+        let outliers = sorted
+            .iter()
+            .filter(|&&x| (x as f64) < q1 - 1.5 * iqr || (x as f64) > q3 + 1.5 * iqr)
+            .count() as u128;
+
+        Self {
+            samples,
+            min: nanos_to_duration(sorted[0]),
+            mean: nanos_to_duration(mean),
+            median: nanos_to_duration(median),
+            p95: nanos_to_duration(percentile(&sorted, 95)),
+            stddev: float_nanos_to_duration(stddev),
+            ci95: float_nanos_to_duration(1.96 * stddev / (samples as f64).sqrt()),
+            outliers,
+        }
+    }
+}
+
+/// Returns the `p`-th percentile of a sorted, non-empty list using the nearest-rank method.
+fn percentile(sorted: &[u128], p: usize) -> u128 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+/// The outcome of running a single part of a solution.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub stats: Stats,
+    /// The panic message or timeout of a part that failed to run.
+    pub error: Option<String>,
+    /// The allocations of the part if they were counted, see [`RunOptions::mem`].
+    pub memory: Option<MemStats>,
+}
+
+/// Why a part did not finish running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Failure {
+    #[must_use]
+    pub fn status(&self) -> Status {
+        match self {
+            Failure::Panicked(_) => Status::Panicked,
+            Failure::TimedOut(_) => Status::TimedOut,
+        }
+    }
+
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self {
+            Failure::Panicked(message) => message.clone(),
+            Failure::TimedOut(timeout) => format!("no answer after {timeout:.1?}"),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(_) => write!(f, "✖ panic: {}", self.message()),
+            Failure::TimedOut(_) => write!(f, "⏱ timeout: {}", self.message()),
+        }
+    }
+}
+
+pub fn run_part<T: Display + Send + 'static>(
+    func: impl Fn(&str) -> Option<T> + Send + Sync + 'static,
+    input: Arc<str>,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
     let part_str = format!("Part {part}");
+    let is_text = options.format == Format::Text;
+    // intermediate results and benchmark progress are only useful when shown live.
+    let show_progress = is_text && !is_capturing();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let run = run_timed(func, input, options, show_progress, |result| {
+        if show_progress {
+            print_result(result, &part_str, "");
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, stats, memory) = match run {
+        Ok(run) => run,
+        Err(failure) => {
+            if is_text {
+                write_output(&format!("{part_str}: {failure}\n"));
+            }
+            return PartResult {
+                puzzle,
+                part,
+                answer: None,
+                status: failure.status(),
+                stats: Stats::default(),
+                error: Some(failure.message()),
+                memory: None,
+            };
+        }
+    };
+
+    let answer = result.as_ref().map(ToString::to_string);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let verdict = match &answer {
+        Some(answer) if options.verify => verify_result(answer, puzzle, part),
+        _ => Verdict::Unknown,
+    };
+
+    if is_text {
+        let suffix = format!(
+            "{}{}{}",
+            format_duration(&stats),
+            memory.as_ref().map(format_memory).unwrap_or_default(),
+            format_verdict(&verdict)
+        );
+        print_result(&result, &part_str, &suffix);
+    }
+
+    if let Some(answer) = &answer {
+        if options.submit == Some(part) {
+            submit_result(answer, puzzle, part);
+        }
+    }
+
+    PartResult {
+        puzzle,
+        part,
+        status: match (&answer, verdict) {
+            (None, _) => Status::Unsolved,
+            (Some(_), Verdict::Correct) => Status::Correct,
+            (Some(_), Verdict::Incorrect { .. }) => Status::Incorrect,
+            (Some(_), Verdict::Unknown) => Status::Solved,
+        },
+        answer,
+        stats,
+        error: None,
+        memory,
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
-    input: I,
+/// Checks an answer against the accepted answer stored for the puzzle.
+fn verify_result(answer: &str, puzzle: PuzzleId, part: u8) -> Verdict {
+    match Answers::load(puzzle) {
+        Ok(answers) => answers.verify(part, answer),
+        Err(e) => {
+            eprintln!("Failed to verify answer: {e}");
+            Verdict::Unknown
+        }
+    }
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => " ✔".into(),
+        Verdict::Incorrect { expected } => format!(" ✘ (expected {expected})"),
+        Verdict::Unknown => String::new(),
+    }
+}
+
+/// Stack size of the threads that parts run on, as recursive solutions can run out of the default 2 MiB.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Messages from the thread that runs a part to the thread that waits for it.
+enum Message<T> {
+    /// The first run finished, with its duration and allocations.
+    Ran(Result<(T, Duration, Option<MemStats>), Failure>),
+    /// A run of the benchmark finished.
+    Sampled,
+    /// The benchmark finished.
+    Benched(Result<Stats, Failure>),
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched after the first run, see [`bench`].
+///
+/// All runs happen on their own thread, so a panic or a single run exceeding `options.timeout` is reported as
+/// [`Failure`]. With `options.mem`, the allocations of the first run are counted.
+/// A part that times out can not be stopped and keeps running in the background until the process exits.
+fn run_timed<T: Send + 'static>(
+    func: impl Fn(&str) -> T + Send + Sync + 'static,
+    input: Arc<str>,
+    options: &RunOptions,
+    show_progress: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> Result<(T, Stats, Option<MemStats>), Failure> {
+    let (sender, receiver) = mpsc::channel();
+
+    let mem = options.mem;
+    let bench_config = options.is_timed.then_some(options.bench);
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // the receiver is gone if the part timed out, so sending errors are ignored.
+            let run = panic::catch_unwind(AssertUnwindSafe(|| {
+                let timer = Instant::now();
+                let (result, memory) = if mem {
+                    memory::measure(|| func(&input))
+                } else {
+                    (func(&input), None)
+                };
+                (result, timer.elapsed(), memory)
+            }));
+            let base_time = match run {
+                Ok((result, base_time, memory)) => {
+                    let _ = sender.send(Message::Ran(Ok((result, base_time, memory))));
+                    base_time
+                }
+                Err(payload) => {
+                    let _ = sender.send(Message::Ran(Err(Failure::Panicked(panic_message(
+                        &*payload,
+                    )))));
+                    return;
+                }
+            };
+
+            if let Some(config) = bench_config {
+                let stats = panic::catch_unwind(AssertUnwindSafe(|| {
+                    bench(
+                        |input: &str| func(input),
+                        &*input,
+                        &base_time,
+                        config,
+                        || {
+                            let _ = sender.send(Message::Sampled);
+                        },
+                    )
+                }));
+                let stats = stats.map_err(|payload| Failure::Panicked(panic_message(&*payload)));
+                let _ = sender.send(Message::Benched(stats));
+            }
+        })
+        .expect("failed to spawn thread");
+
+    // every run of the part has to finish within the timeout.
+    let receive = || match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => Failure::TimedOut(timeout),
+            mpsc::RecvTimeoutError::Disconnected => Failure::Panicked("thread exited".into()),
+        }),
+        None => receiver
+            .recv()
+            .map_err(|_| Failure::Panicked("thread exited".into())),
+    };
+
+    let Message::Ran(run) = receive()? else {
+        unreachable!("the first run is reported first");
+    };
+    let (result, base_time, memory) = run?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.is_timed {
+        if show_progress {
+            write_output(&format!(" > {ANSI_ITALIC}benching{ANSI_RESET}"));
+        }
+        loop {
+            match receive()? {
+                Message::Sampled => {}
+                Message::Benched(stats) => break stats?,
+                Message::Ran(_) => unreachable!("the part only runs once before the benchmark"),
+            }
+        }
     } else {
-        (base_time, 1)
+        Stats::from_samples(&[base_time])
     };
 
-    (result, run.0, run.1)
+    Ok((result, stats, memory))
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Extracts the message of a panic, which is a `&str` or a `String` for the `panic!` family of macros.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".into())
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Benchmarks a function. The function is warmed up for `config.warm_up`, after which it is measured for approx. `config.time_budget`, with at least 10 and at most 10.000 samples.
+/// `on_sample` is called after every run.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: BenchConfig,
+    on_sample: impl Fn(),
+) -> Stats {
+    // warm up caches and the branch predictor, and use the warm-up runs to get a better estimate of the iteration time.
+    let mut warm_up_iterations = 0;
+    let warm_up_timer = Instant::now();
+    while warm_up_timer.elapsed() < config.warm_up {
+        func(input.clone());
+        on_sample();
+        warm_up_iterations += 1;
+    }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let iteration_time = warm_up_timer
+        .elapsed()
+        .as_nanos()
+        .checked_div(warm_up_iterations)
+        .unwrap_or(base_time.as_nanos());
+
+    let bench_iterations =
+        (config.time_budget.as_nanos() / cmp::max(iteration_time, 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,29 +545,46 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         let timer = Instant::now();
         func(cloned);
         timers.push(timer.elapsed());
+        on_sample();
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+#[allow(clippy::cast_possible_truncation)]
+fn nanos_to_duration(nanos: u128) -> Duration {
+    Duration::from_nanos(nanos as u64)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn float_nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        return format!(" ({:.1?})", stats.median);
     }
+
+    let outliers = match stats.outliers {
+        0 => String::new(),
+        1 => ", 1 outlier".into(),
+        n => format!(", {n} outliers"),
+    };
+
+    format!(
+        " ({:.1?} median, p95 {:.1?}, σ {:.1?} @ {} samples{outliers})",
+        stats.median, stats.p95, stats.stddev, stats.samples
+    )
+}
+
+fn format_memory(memory: &MemStats) -> String {
+    format!(
+        " [{} peak, {} allocations, {} allocated]",
+        memory::format_bytes(memory.peak_bytes),
+        memory.allocations,
+        memory::format_bytes(memory.allocated_bytes)
+    )
 }
 
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
@@ -100,68 +595,336 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write_output(&str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    write_output("\r");
+                    write_output(&format!("{str}\n"));
+                    write_output(&format!("{result}\n"));
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write_output(&str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    write_output("\r");
+                    write_output(&format!("{str}\n"));
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                write_output(&format!("{part}: ✖"));
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                write_output("\r");
+                write_output(&format!("{part}: ✖             \n"));
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+thread_local! {
+    /// The output of the runner on this thread while it is captured, see [`capture_output`].
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+/// Runs `f` and returns the output the runner printed on this thread in the meantime, instead of writing it to stdout.
+/// Used to print the output of days that run in parallel in order.
+pub fn capture_output<R>(f: impl FnOnce() -> R) -> (R, String) {
+    CAPTURED.with_borrow_mut(|captured| *captured = Some(String::new()));
+    let result = f();
+    let output = CAPTURED.with_borrow_mut(Option::take).unwrap_or_default();
+    (result, output)
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
+fn is_capturing() -> bool {
+    CAPTURED.with_borrow(Option::is_some)
+}
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+/// Writes to stdout, or to the captured output of this thread, see [`capture_output`].
+pub fn write_output(s: &str) {
+    let captured = CAPTURED.with_borrow_mut(|captured| {
+        captured
+            .as_mut()
+            .map(|captured| captured.push_str(s))
+            .is_some()
+    });
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if !captured {
+        print!("{s}");
+        let _ = stdout().flush();
+    }
+}
+
+/// Try to submit one part of the solution, via HTTP if a session is configured and via aoc-cli otherwise.
+/// Answers that are known to be wrong are not submitted, the outcome of a submission is recorded in the answers file.
+fn submit_result(result: &str, puzzle: PuzzleId, part: u8) {
+    let mut answers = match Answers::load(puzzle) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
     };
 
-    if part_submit != part {
-        return None;
+    let submitted =
+        submit_guess(
+            answers.part_mut(part),
+            result,
+            || match aoc_client::Client::from_env() {
+                Ok(client) => {
+                    println!("Submitting result...");
+                    client
+                        .submit(puzzle, part, result)
+                        .map_err(|e| eprintln!("failed to submit result: {e}"))
+                        .ok()
+                }
+                Err(aoc_client::Error::MissingSession) => submit_with_aoc_cli(result, puzzle, part),
+                Err(e) => {
+                    eprintln!("{e}");
+                    None
+                }
+            },
+        );
+
+    match submitted {
+        Err(rejection) => eprintln!("Not submitting {result}: {rejection}."),
+        Ok(None) => {}
+        Ok(Some(outcome)) => {
+            println!("Submission result: {outcome}.");
+            if let Err(e) = answers.save(puzzle) {
+                eprintln!("Failed to record submission: {e}");
+            }
+        }
     }
+}
 
+/// Calls `submit` unless earlier submissions rule out `result`, and records the outcome.
+/// `submit` returns `None` if the submission failed.
+fn submit_guess(
+    answers: &mut PartAnswers,
+    result: &str,
+    submit: impl FnOnce() -> Option<Outcome>,
+) -> Result<Option<Outcome>, Rejection> {
+    answers.check_guess(result)?;
+
+    let outcome = submit();
+    if let Some(outcome) = outcome {
+        answers.record(result, outcome);
+    }
+    Ok(outcome)
+}
+
+/// Submits a result via aoc-cli, which is used if no session is configured.
+fn submit_with_aoc_cli(result: &str, puzzle: PuzzleId, part: u8) -> Option<Outcome> {
     if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        eprintln!("no session found and command \"aoc\" not found or not callable. Set ADVENT_OF_CODE_SESSION or try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = match aoc_cli::submit(puzzle, part, result) {
+        Ok(output) | Err(aoc_cli::AocCommandError::BadExitStatus(output)) => output,
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            return None;
+        }
+    };
+
+    let response = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(Outcome::parse(&response))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::{
+        capture_output, run_part, submit_guess, write_output, BenchConfig, Failure, RunOptions,
+        Stats, Status,
+    };
+    use crate::template::{
+        answers::{PartAnswers, Rejection},
+        aoc_client::Client,
+        mock_server::{Fixture, MockServer},
+        submission::Outcome,
+    };
+    use crate::{day, year, PuzzleId};
+
+    fn from_nanos(nanos: &[u64]) -> Stats {
+        let timers: Vec<Duration> = nanos.iter().map(|&n| Duration::from_nanos(n)).collect();
+        Stats::from_samples(&timers)
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = from_nanos(&[42]);
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let stats = from_nanos(&[40, 10, 30, 20]);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.stddev, Duration::from_nanos(11));
+        assert_eq!(stats.ci95, Duration::from_nanos(11));
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let mut nanos = vec![100; 18];
+        nanos.extend([101, 99, 5000]);
+        let stats = from_nanos(&nanos);
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.outliers, 3);
+    }
+
+    #[test]
+    fn submits_only_promising_guesses() {
+        let server = MockServer::start("session", vec![Fixture::day_one()]);
+        let client = Client::new(&server.url(), "session");
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let mut answers = PartAnswers::default();
+
+        let submit = |answers: &mut PartAnswers, result: &str| {
+            submit_guess(answers, result, || client.submit(puzzle, 1, result).ok())
+        };
+
+        assert_eq!(submit(&mut answers, "100"), Ok(Some(Outcome::TooLow)));
+        assert_eq!(submit(&mut answers, "100"), Err(Rejection::KnownWrong));
+        assert_eq!(
+            submit(&mut answers, "99"),
+            Err(Rejection::TooLow { bound: 100 })
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        server.set_rate_limited(true);
+        assert_eq!(submit(&mut answers, "142"), Ok(None));
+        assert_eq!(answers.answer, None);
+
+        server.set_rate_limited(false);
+        assert_eq!(submit(&mut answers, "142"), Ok(Some(Outcome::Correct)));
+        assert_eq!(answers.answer.as_deref(), Some("142"));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn isolates_panics_and_timeouts() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let input: Arc<str> = "1\n2\n".into();
+        let options = RunOptions {
+            format: crate::template::report::Format::Json,
+            timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+
+        let result = run_part(
+            |input: &str| Some(input.lines().count()),
+            Arc::clone(&input),
+            puzzle,
+            1,
+            &options,
+        );
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!(result.answer.as_deref(), Some("2"));
+
+        let result = run_part(
+            |input: &str| input.parse::<u32>().ok().or_else(|| panic!("no number")),
+            Arc::clone(&input),
+            puzzle,
+            1,
+            &options,
+        );
+        assert_eq!(result.status, Status::Panicked);
+        assert_eq!(result.error.as_deref(), Some("no number"));
+
+        let result = run_part(
+            |_: &str| -> Option<u32> {
+                std::thread::sleep(Duration::from_secs(1));
+                None
+            },
+            input,
+            puzzle,
+            2,
+            &options,
+        );
+        assert_eq!(result.status, Status::TimedOut);
+        assert_eq!(
+            Failure::TimedOut(Duration::from_millis(50)).to_string(),
+            "⏱ timeout: no answer after 50.0ms"
+        );
+    }
+
+    #[test]
+    fn isolates_benchmark_runs() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let options = RunOptions {
+            format: crate::template::report::Format::Json,
+            is_timed: true,
+            timeout: Some(Duration::from_millis(50)),
+            bench: BenchConfig {
+                warm_up: Duration::from_millis(1),
+                time_budget: Duration::from_millis(5),
+            },
+            ..RunOptions::default()
+        };
+        // a part that behaves differently after its first run.
+        let part = |later: fn()| {
+            let runs = AtomicUsize::new(0);
+            move |_: &str| {
+                if runs.fetch_add(1, Ordering::SeqCst) > 0 {
+                    later();
+                }
+                Some(1)
+            }
+        };
+
+        let result = run_part(part(|| ()), "".into(), puzzle, 1, &options);
+        assert_eq!(result.status, Status::Solved);
+        assert!(result.stats.samples >= 10);
+
+        let result = run_part(
+            part(|| panic!("second run")),
+            "".into(),
+            puzzle,
+            1,
+            &options,
+        );
+        assert_eq!(result.status, Status::Panicked);
+        assert_eq!(result.error.as_deref(), Some("second run"));
+
+        let result = run_part(
+            part(|| std::thread::sleep(Duration::from_secs(1))),
+            "".into(),
+            puzzle,
+            1,
+            &options,
+        );
+        assert_eq!(result.status, Status::TimedOut);
+    }
+
+    #[test]
+    fn captures_output_per_thread() {
+        let (answer, output) = capture_output(|| {
+            write_output("Day 01\n");
+            let other = std::thread::spawn(|| capture_output(|| write_output("Day 02\n")).1);
+            assert_eq!(other.join().unwrap(), "Day 02\n");
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(output, "Day 01\n");
+    }
 }
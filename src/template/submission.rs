@@ -0,0 +1,130 @@
+/// Module that interprets the response to an answer submission.
+use std::{fmt::Display, time::Duration};
+
+use regex::Regex;
+
+/// The response of advent of code to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently. Contains the remaining time to wait, if it could be parsed.
+    Wait(Option<Duration>),
+    /// The part has already been completed, or is not unlocked yet.
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl Outcome {
+    /// Parses the text of a submission response, e.g. the output of `aoc submit`.
+    #[must_use]
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Self::Correct
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                Self::TooHigh
+            } else if response.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if response.contains("You gave an answer too recently") {
+            Self::Wait(parse_wait_time(response))
+        } else if response.contains("Did you already complete it?") {
+            Self::AlreadyCompleted
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "the answer is correct"),
+            Outcome::TooHigh => write!(f, "the answer is too high"),
+            Outcome::TooLow => write!(f, "the answer is too low"),
+            Outcome::Incorrect => write!(f, "the answer is not correct"),
+            Outcome::Wait(Some(duration)) => {
+                write!(f, "an answer was submitted too recently, wait {duration:?}")
+            }
+            Outcome::Wait(None) => write!(f, "an answer was submitted too recently"),
+            Outcome::AlreadyCompleted => write!(f, "the part has already been completed"),
+            Outcome::Unknown => write!(f, "the response could not be interpreted"),
+        }
+    }
+}
+
+/// Parses the wait time from a message like "You have 4m 30s left to wait."
+fn parse_wait_time(response: &str) -> Option<Duration> {
+    let re = Regex::new(r"You have (?:(\d+)m)?\s*(?:(\d+)s)? left to wait").unwrap();
+    let captures = re.captures(response)?;
+
+    let minutes: u64 = captures
+        .get(1)
+        .map_or(Some(0), |m| m.as_str().parse().ok())?;
+    let seconds: u64 = captures
+        .get(2)
+        .map_or(Some(0), |m| m.as_str().parse().ok())?;
+
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Outcome;
+
+    #[test]
+    fn parses_correct() {
+        let response =
+            "That's the right answer! You are one gold star closer to restoring snow operations.";
+        assert_eq!(Outcome::parse(response), Outcome::Correct);
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        assert_eq!(
+            Outcome::parse(
+                "That's not the right answer; your answer is too high. If you're stuck, ..."
+            ),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer. If you're stuck, make sure you're using the full input data"),
+            Outcome::Incorrect
+        );
+    }
+
+    #[test]
+    fn parses_wait_time() {
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 4m 30s left to wait."),
+            Outcome::Wait(Some(Duration::from_secs(270)))
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; You have 35s left to wait."),
+            Outcome::Wait(Some(Duration::from_secs(35)))
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently."),
+            Outcome::Wait(None)
+        );
+    }
+
+    #[test]
+    fn parses_already_completed() {
+        let response =
+            "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Outcome::parse(response), Outcome::AlreadyCompleted);
+        assert_eq!(Outcome::parse("<html></html>"), Outcome::Unknown);
+    }
+}